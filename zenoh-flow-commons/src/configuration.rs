@@ -12,9 +12,10 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-use crate::merge::IMergeOverwrite;
-use serde::{Deserialize, Serialize};
-use std::{ops::Deref, sync::Arc};
+use crate::{merge::IMergeOverwrite, Result};
+use anyhow::{anyhow, Context};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{ops::Deref, sync::Arc, time::Duration};
 
 /// A `Configuration` is a recursive key-value structure that allows modifying the behaviour of a node without altering
 /// its implementation.
@@ -85,6 +86,117 @@ impl Deref for Configuration {
     }
 }
 
+impl Configuration {
+    /// Attempts to deserialise the whole configuration as a `T`.
+    ///
+    /// For nodes expecting many configuration keys, deriving [Deserialize] on a dedicated structure and calling this
+    /// method is usually more convenient than retrieving each key separately.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the configuration could not be deserialised as a `T`, e.g. if a required
+    /// field is missing or has an unexpected type.
+    pub fn to_typed<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(&*self.0).context(format!(
+            "Failed to deserialise the configuration as a < {} >",
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Attempts to retrieve the value associated with the provided top-level `key` and to deserialise it as a `T`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - there is no value associated with the `key`,
+    /// - the value could not be deserialised as a `T`.
+    pub fn try_get<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        let value = self
+            .get(key)
+            .ok_or_else(|| anyhow!("Missing configuration key < {} >", key))?;
+
+        T::deserialize(value).context(format!(
+            "Configuration key < {} > does not have the expected type < {} >",
+            key,
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Attempts to retrieve the value associated with the provided top-level `key`, returning `default` if there is
+    /// none.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if a value is associated with the `key` but could not be deserialised as a
+    /// `T`.
+    pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T> {
+        match self.get(key) {
+            Some(_) => self.try_get(key),
+            None => Ok(default),
+        }
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a `u64`.
+    ///
+    /// See [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_u64(&self, key: &str) -> Result<u64> {
+        self.try_get(key)
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a `f64`.
+    ///
+    /// See [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_f64(&self, key: &str) -> Result<f64> {
+        self.try_get(key)
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a `bool`.
+    ///
+    /// See [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_bool(&self, key: &str) -> Result<bool> {
+        self.try_get(key)
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a string.
+    ///
+    /// See [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_str(&self, key: &str) -> Result<&str> {
+        self.get(key)
+            .ok_or_else(|| anyhow!("Missing configuration key < {} >", key))?
+            .as_str()
+            .ok_or_else(|| {
+                anyhow!(
+                    "Configuration key < {} > does not have the expected type < &str >",
+                    key
+                )
+            })
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a [Duration], the value being expressed
+    /// in milliseconds.
+    ///
+    /// See [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_duration_ms(&self, key: &str) -> Result<Duration> {
+        self.get_u64(key).map(Duration::from_millis)
+    }
+
+    /// Attempts to retrieve the value associated with the provided `key` as a [Duration], the value being expressed
+    /// with a unit suffix, e.g. "500ms" or "1s".
+    ///
+    /// See [deserialize_duration](crate::deserialize_duration()) for the supported formats and
+    /// [try_get](Configuration::try_get()) for the possible errors.
+    pub fn get_duration(&self, key: &str) -> Result<Duration> {
+        crate::deserialize_duration(
+            self.get(key)
+                .ok_or_else(|| anyhow!("Missing configuration key < {} >", key))?,
+        )
+        .context(format!(
+            "Configuration key < {} > is not a valid duration",
+            key
+        ))
+    }
+}
+
 impl IMergeOverwrite for Configuration {
     fn merge_overwrite(self, other: Self) -> Self {
         if self == Configuration::default() {
@@ -148,4 +260,72 @@ mod tests {
             Configuration::default().merge_overwrite(Configuration::default())
         )
     }
+
+    #[test]
+    fn test_to_typed() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct MyConfiguration {
+            rate: u64,
+            mode: String,
+            #[serde(default)]
+            verbose: bool,
+        }
+
+        let configuration = Configuration::from(json!({ "rate": 10, "mode": "fast" }));
+        assert_eq!(
+            MyConfiguration {
+                rate: 10,
+                mode: "fast".into(),
+                verbose: false,
+            },
+            configuration.to_typed::<MyConfiguration>().unwrap()
+        );
+
+        assert!(Configuration::from(json!({ "rate": 10 }))
+            .to_typed::<MyConfiguration>()
+            .is_err());
+        assert!(Configuration::default()
+            .to_typed::<MyConfiguration>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_typed_accessors() {
+        let configuration = Configuration::from(json!({
+            "rate": 10,
+            "gain": 0.5,
+            "verbose": true,
+            "mode": "fast",
+            "interval": 250,
+            "timeout": "1s",
+            "ports": ["in-0", "in-1"],
+        }));
+
+        assert_eq!(10, configuration.get_u64("rate").unwrap());
+        assert_eq!(0.5, configuration.get_f64("gain").unwrap());
+        assert!(configuration.get_bool("verbose").unwrap());
+        assert_eq!("fast", configuration.get_str("mode").unwrap());
+        assert_eq!(
+            Duration::from_millis(250),
+            configuration.get_duration_ms("interval").unwrap()
+        );
+        assert_eq!(
+            Duration::from_secs(1),
+            configuration.get_duration("timeout").unwrap()
+        );
+        assert!(configuration.get_duration("interval").is_err());
+        assert_eq!(
+            vec!["in-0".to_string(), "in-1".to_string()],
+            configuration.try_get::<Vec<String>>("ports").unwrap()
+        );
+
+        let error = configuration.get_u64("missing").unwrap_err();
+        assert_eq!("Missing configuration key < missing >", error.to_string());
+        assert!(configuration.get_u64("mode").is_err());
+        assert!(configuration.get_str("rate").is_err());
+
+        assert_eq!(42, configuration.get_or("missing", 42u64).unwrap());
+        assert_eq!(10, configuration.get_or("rate", 42u64).unwrap());
+        assert!(configuration.get_or("mode", 42u64).is_err());
+    }
 }
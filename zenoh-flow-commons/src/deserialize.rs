@@ -12,13 +12,13 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-//! This module exposes the functions [deserialize_size] and [deserialize_time] that are used
+//! This module exposes the functions [deserialize_size], [deserialize_time] and [deserialize_duration] that are used
 //! throughout Zenoh-Flow to "parse" values used to express time or size.
 //!
 //! The external crates [bytesize] and [humantime] are leveraged for these purposes.
 
 use serde::Deserializer;
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
 use zenoh_keyexpr::OwnedKeyExpr;
 
 /// Deserialise, from a String, an `Arc<str>` that is guaranteed to be a valid Zenoh-Flow [NodeId](crate::NodeId) or
@@ -121,10 +121,32 @@ where
     })
 }
 
+/// Deserialise a [Duration] leveraging the [humantime] crate.
+///
+/// This allows writing, in a descriptor, `deadline: 500ms` or `deadline: 1s` instead of a raw number. The supported
+/// suffixes include, among others, "ms", "s" and "m". For more examples, see the [humantime] crate.
+///
+/// # Errors
+///
+/// See the [humantime] documentation.
+pub fn deserialize_duration<'de, D>(deserializer: D) -> std::result::Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let buf: String = serde::de::Deserialize::deserialize(deserializer)?;
+    buf.parse::<humantime::Duration>()
+        .map(Into::into)
+        .map_err(|e| {
+            serde::de::Error::custom(format!("Unable to parse < {} > as a duration: {}", buf, e))
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Deserialize;
+    use std::time::Duration;
 
+    use super::deserialize_duration;
     use crate::NodeId;
 
     #[derive(Deserialize, Debug)]
@@ -183,4 +205,25 @@ mod tests {
 "#;
         assert!(serde_json::from_str::<TestStruct>(json_str).is_ok());
     }
+
+    #[derive(Deserialize, Debug)]
+    pub struct TestDeadline {
+        #[serde(deserialize_with = "deserialize_duration")]
+        pub deadline: Duration,
+    }
+
+    #[test]
+    fn test_deserialize_duration() {
+        let deadline = serde_yaml::from_str::<TestDeadline>("deadline: 1s").unwrap();
+        assert_eq!(Duration::from_secs(1), deadline.deadline);
+
+        let deadline = serde_yaml::from_str::<TestDeadline>("deadline: 500ms").unwrap();
+        assert_eq!(Duration::from_millis(500), deadline.deadline);
+
+        let deadline = serde_yaml::from_str::<TestDeadline>("deadline: 2m").unwrap();
+        assert_eq!(Duration::from_secs(120), deadline.deadline);
+
+        assert!(serde_yaml::from_str::<TestDeadline>("deadline: 500").is_err());
+        assert!(serde_yaml::from_str::<TestDeadline>("deadline: soon").is_err());
+    }
 }
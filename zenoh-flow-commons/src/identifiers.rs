@@ -60,6 +60,33 @@ impl From<&str> for NodeId {
     }
 }
 
+impl AsRef<str> for NodeId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Creates a [NodeId] from a string slice.
+///
+/// This macro is a shorthand for `NodeId::from(...)` that allows defining the identifiers of the nodes once, as
+/// constants, and reusing them throughout the code.
+///
+/// # Example
+///
+/// ```
+/// use zenoh_flow_commons::{node, NodeId};
+///
+/// const SOURCE: &str = "source";
+///
+/// assert_eq!(node!(SOURCE), NodeId::from("source"));
+/// ```
+#[macro_export]
+macro_rules! node {
+    ($id:expr) => {
+        $crate::NodeId::from($id)
+    };
+}
+
 /// A `PortId` identifies an `Input` or an `Output` of a Node.
 ///
 /// A `PortId` additionally satisfies the following constraints:
@@ -104,6 +131,33 @@ impl From<&str> for PortId {
     }
 }
 
+impl AsRef<str> for PortId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Creates a [PortId] from a string slice.
+///
+/// This macro is a shorthand for `PortId::from(...)` that allows defining the identifiers of the ports once, as
+/// constants, and reusing them throughout the code.
+///
+/// # Example
+///
+/// ```
+/// use zenoh_flow_commons::{port, PortId};
+///
+/// const OUTPUT: &str = "out";
+///
+/// assert_eq!(port!(OUTPUT), PortId::from("out"));
+/// ```
+#[macro_export]
+macro_rules! port {
+    ($id:expr) => {
+        $crate::PortId::from($id)
+    };
+}
+
 /// A `RuntimeId` uniquely identifies a Zenoh-Flow runtime within a Zenoh network.
 ///
 /// The `RuntimeId` structure simply wraps a [ZenohId]. Similar to a Uuid, this identifier is (with a high probability)
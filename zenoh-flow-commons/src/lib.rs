@@ -21,7 +21,7 @@ mod configuration;
 pub use configuration::Configuration;
 
 mod deserialize;
-pub use deserialize::deserialize_id;
+pub use deserialize::{deserialize_duration, deserialize_id};
 
 mod identifiers;
 pub use identifiers::{InstanceId, NodeId, PortId, RuntimeId};
@@ -33,10 +33,10 @@ mod shared_memory;
 pub use shared_memory::SharedMemoryConfiguration;
 
 mod utils;
-pub use utils::try_parse_from_file;
+pub use utils::{try_parse_from_file, try_parse_from_str, DescriptorFormat};
 
 mod vars;
-pub use vars::{parse_vars, Vars};
+pub use vars::{parse_vars, parse_vars_list, VarValue, Vars, VarsDiff};
 
 /// Zenoh-Flow's result type.
 pub type Result<T> = std::result::Result<T, anyhow::Error>;
@@ -12,16 +12,44 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
+use crate::vars::{new_registry, template_data};
 use crate::{IMergeOverwrite, Result, Vars};
 use anyhow::{bail, Context};
-use handlebars::Handlebars;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::Read;
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::rc::Rc;
 
-/// Given the [Path] of a file, return the function we should call to deserialize an instance of `N`.
+/// The formats in which a descriptor can be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptorFormat {
+    Json,
+    Yaml,
+}
+
+impl DescriptorFormat {
+    /// Returns the function we should call to deserialize an instance of `N` written in this format.
+    pub(crate) fn deserializer<N>(&self) -> fn(&str) -> Result<N>
+    where
+        N: for<'a> Deserialize<'a>,
+    {
+        match self {
+            DescriptorFormat::Json => |buf| {
+                serde_json::from_str::<N>(buf)
+                    .context(format!("Failed to deserialize from JSON:\n{}", buf))
+            },
+            DescriptorFormat::Yaml => |buf| {
+                serde_yaml::from_str::<N>(buf)
+                    .context(format!("Failed to deserialize from YAML:\n{}", buf))
+            },
+        }
+    }
+}
+
+/// Given the [Path] of a file, return the [DescriptorFormat] in which it is written.
 ///
-/// This function will look at the extension of the [Path] to decide on a deserializer.
+/// This function will look at the extension of the [Path] to decide on a format.
 ///
 /// # Errors
 ///
@@ -29,19 +57,10 @@ use std::path::{Path, PathBuf};
 /// - ".yml"
 /// - ".yaml"
 /// - ".json"
-pub(crate) fn deserializer<N>(path: &PathBuf) -> Result<fn(&str) -> Result<N>>
-where
-    N: for<'a> Deserialize<'a>,
-{
+pub(crate) fn format(path: &Path) -> Result<DescriptorFormat> {
     match path.extension().and_then(|ext| ext.to_str()) {
-        Some("json") => Ok(|buf| {
-            serde_json::from_str::<N>(buf)
-                .context(format!("Failed to deserialize from JSON:\n{}", buf))
-        }),
-        Some("yml") | Some("yaml") => Ok(|buf| {
-            serde_yaml::from_str::<N>(buf)
-                .context(format!("Failed to deserialize from YAML:\n{}", buf))
-        }),
+        Some("json") => Ok(DescriptorFormat::Json),
+        Some("yml") | Some("yaml") => Ok(DescriptorFormat::Yaml),
         Some(extension) => bail!(
             r#"
 Unsupported file extension < {} > in:
@@ -59,6 +78,149 @@ Currently supported file extensions are:
     }
 }
 
+/// Returns the list of values `string` should be replaced with, if it is the expression of a var holding a list.
+///
+/// # Errors
+///
+/// This function will return an error if `string` contains the expression of a var holding a list but is not limited
+/// to it: a list cannot be part of a string.
+fn try_get_list<'a>(
+    string: &str,
+    lists: &HashMap<String, &'a [Rc<str>]>,
+) -> Result<Option<&'a [Rc<str>]>> {
+    if let Some(values) = lists.get(string) {
+        return Ok(Some(values));
+    }
+
+    if let Some(expression) = lists.keys().find(|&expression| string.contains(expression)) {
+        bail!(
+            "The var < {} > holds a list of values, it can only be used as a whole string, found: {}",
+            expression,
+            string
+        );
+    }
+
+    Ok(None)
+}
+
+/// Replaces, in the JSON `value`, the strings that are the expression of a var holding a list with its values.
+fn try_expand_lists_json(
+    value: &mut serde_json::Value,
+    lists: &HashMap<String, &[Rc<str>]>,
+) -> Result<()> {
+    match value {
+        serde_json::Value::String(string) => {
+            if let Some(values) = try_get_list(string, lists)? {
+                *value = values.iter().map(|value| value.as_ref()).collect();
+            }
+        }
+        serde_json::Value::Array(values) => values
+            .iter_mut()
+            .try_for_each(|value| try_expand_lists_json(value, lists))?,
+        serde_json::Value::Object(object) => object
+            .values_mut()
+            .try_for_each(|value| try_expand_lists_json(value, lists))?,
+        serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Replaces, in the YAML `value`, the strings that are the expression of a var holding a list with its values.
+fn try_expand_lists_yaml(
+    value: &mut serde_yaml::Value,
+    lists: &HashMap<String, &[Rc<str>]>,
+) -> Result<()> {
+    match value {
+        serde_yaml::Value::String(string) => {
+            if let Some(values) = try_get_list(string, lists)? {
+                *value = values.iter().map(|value| value.as_ref()).collect();
+            }
+        }
+        serde_yaml::Value::Sequence(values) => values
+            .iter_mut()
+            .try_for_each(|value| try_expand_lists_yaml(value, lists))?,
+        serde_yaml::Value::Mapping(mapping) => mapping
+            .values_mut()
+            .try_for_each(|value| try_expand_lists_yaml(value, lists))?,
+        serde_yaml::Value::Tagged(tagged) => try_expand_lists_yaml(&mut tagged.value, lists)?,
+        serde_yaml::Value::Null | serde_yaml::Value::Bool(_) | serde_yaml::Value::Number(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Attempts to parse an instance of `N` from the `rendered` descriptor, written in the provided `format`, after having
+/// expanded the vars holding a list of values (see [Vars]).
+fn try_parse_expanding_lists<N>(
+    rendered: &str,
+    format: DescriptorFormat,
+    lists: &HashMap<String, &[Rc<str>]>,
+) -> Result<N>
+where
+    N: for<'a> Deserialize<'a>,
+{
+    match format {
+        DescriptorFormat::Json => {
+            let mut value = format.deserializer::<serde_json::Value>()(rendered)?;
+            try_expand_lists_json(&mut value, lists)?;
+            serde_json::from_value(value)
+                .context(format!("Failed to deserialize from JSON:\n{}", rendered))
+        }
+        DescriptorFormat::Yaml => {
+            let mut value = format.deserializer::<serde_yaml::Value>()(rendered)?;
+            try_expand_lists_yaml(&mut value, lists)?;
+            serde_yaml::from_value(value)
+                .context(format!("Failed to deserialize from YAML:\n{}", rendered))
+        }
+    }
+}
+
+/// Attempts to parse an instance of `N` from the provided `content`, written in the provided `format`, overwriting (or
+/// complementing) the [Vars] declared in said content with the provided `vars`.
+///
+/// This function is the counterpart of [try_parse_from_file] for descriptors that do not live in a file, for instance
+/// when they are generated or received over the network.
+///
+/// # Errors
+///
+/// The parsing can fail for several reasons (listed in sequential order):
+/// - parsing the [Vars] section failed (if there is one),
+/// - resolving the references between the [Vars] failed (e.g. they form a cycle),
+/// - expanding the variables located in the [Vars] section failed (if there are any) --- see the documentation
+///   [handlebars] for a more complete list of reasons,
+/// - a var holding a list of values is used within a string,
+/// - parsing an instance of `N` failed.
+pub fn try_parse_from_str<N>(
+    content: &str,
+    format: DescriptorFormat,
+    vars: Vars,
+) -> Result<(N, Vars)>
+where
+    N: for<'a> Deserialize<'a>,
+{
+    let declared_vars =
+        format.deserializer::<Vars>()(content).context("Failed to deserialize Vars")?;
+
+    let merged_vars = vars
+        .merge_overwrite(declared_vars)
+        .try_resolve()
+        .context("Failed to resolve Vars")?;
+
+    let rendered_descriptor = new_registry()
+        .render_template(content, &template_data(&merged_vars.values()))
+        .context("Failed to expand descriptor")?;
+
+    let lists = merged_vars.list_expressions();
+    let descriptor = if lists.is_empty() {
+        format.deserializer::<N>()(&rendered_descriptor)?
+    } else {
+        try_parse_expanding_lists(&rendered_descriptor, format, &lists)?
+    };
+
+    Ok((descriptor, merged_vars))
+}
+
 /// Attempts to parse an instance of `N` from the content of the file located at `path`, overwriting (or complementing)
 /// the [Vars] declared in said file with the provided `vars`.
 ///
@@ -76,8 +238,10 @@ Currently supported file extensions are:
 /// - the OS failed to open (in read mode) the file,
 /// - the extension of the file is not supported by Zenoh-Flow (i.e. it's neither a YAML file or a JSON file),
 /// - parsing the [Vars] section failed (if there is one),
+/// - resolving the references between the [Vars] failed (e.g. they form a cycle),
 /// - expanding the variables located in the [Vars] section failed (if there are any) --- see the documentation
 ///   [handlebars] for a more complete list of reasons,
+/// - a var holding a list of values is used within a string,
 /// - parsing an instance of `N` failed.
 pub fn try_parse_from_file<N>(path: impl AsRef<Path>, vars: Vars) -> Result<(N, Vars)>
 where
@@ -97,24 +261,6 @@ where
             path_buf.display()
         ))?;
 
-    let merged_vars = vars.merge_overwrite(
-        deserializer::<Vars>(&path_buf)?(&buf).context("Failed to deserialize Vars")?,
-    );
-
-    let mut handlebars = Handlebars::new();
-    handlebars.set_strict_mode(true);
-
-    let rendered_descriptor = handlebars
-        // NOTE: We have to dereference `merged_vars` (this: `&(*merged_vars)`) and pass the contained `HashMap` such
-        // that `handlebars` can correctly manipulate it.
-        //
-        // We have to have this indirection in the structure such that `serde` can correctly deserialise the descriptor.
-        .render_template(buf.as_str(), &(*merged_vars))
-        .context("Failed to expand descriptor")?;
-
-    Ok((
-        (deserializer::<N>(&path_buf))?(&rendered_descriptor)
-            .context(format!("Failed to deserialize {}", &path_buf.display()))?,
-        merged_vars,
-    ))
+    try_parse_from_str(&buf, format(&path_buf)?, vars)
+        .context(format!("Failed to deserialize {}", &path_buf.display()))
 }
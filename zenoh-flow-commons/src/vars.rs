@@ -12,11 +12,16 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-use crate::IMergeOverwrite;
+use crate::{IMergeOverwrite, Result};
+use anyhow::{bail, Context};
+use handlebars::template::{HelperTemplate, Parameter, Template, TemplateElement};
+use handlebars::Handlebars;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::fmt::Display;
 use std::ops::Deref;
+use std::path::Path;
 use std::rc::Rc;
 
 /// `Vars` is an internal structure that we use to expand the "moustache variables" in a descriptor file.
@@ -61,10 +66,205 @@ use std::rc::Rc;
 ///     - id: my-source
 ///       library: "file:///zenoh-flow/target/{{ BUILD }}/libmy_source.{{ DLL_EXT }}"
 /// ```
+///
+/// # Composition
+///
+/// The value of a var can reference other vars. These references are resolved before the descriptor is rendered:
+///
+/// ```yaml
+///   vars:
+///     BUILD: debug
+///     LIB_DIR: "file:///zenoh-flow/target/{{ BUILD }}"
+/// ```
+///
+/// Vars that reference each other in a cycle (e.g. `A: "{{ B }}"` and `B: "{{ A }}"`) are rejected.
+///
+/// # Lists
+///
+/// A var can also hold a list of values. Such var can only be used as a whole (quoted) string: once the descriptor is
+/// parsed, that string is replaced with the list of values, whatever characters they contain. This works the same in
+/// a YAML and a JSON descriptor:
+///
+/// ```yaml
+///   vars:
+///     PORTS: [ "out-0", "out-1" ]
+///
+///   sources:
+///     - id: my-source
+///       library: "file:///zenoh-flow/target/{{ BUILD }}/libmy_source.{{ DLL_EXT }}"
+///       outputs: "{{ PORTS }}"
+/// ```
+///
+/// The [Vars] dereference to the vars holding a single value, the lists are accessed through [Vars::get_list].
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "VarsSection", into = "VarsSection")]
 pub struct Vars {
-    #[serde(default)]
     vars: Rc<HashMap<Rc<str>, Rc<str>>>,
+    lists: Rc<HashMap<Rc<str>, Vec<Rc<str>>>>,
+}
+
+/// The `vars` section of a descriptor, where single values and lists of values are declared together.
+#[derive(Serialize, Deserialize)]
+struct VarsSection {
+    #[serde(default)]
+    vars: HashMap<Rc<str>, VarValue>,
+}
+
+impl From<VarsSection> for Vars {
+    fn from(section: VarsSection) -> Self {
+        Vars::from_values(section.vars)
+    }
+}
+
+impl From<Vars> for VarsSection {
+    fn from(vars: Vars) -> Self {
+        VarsSection {
+            vars: vars.values(),
+        }
+    }
+}
+
+/// The value of a [Var](Vars): either a single value or a list of values.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum VarValue {
+    Scalar(Rc<str>),
+    List(Vec<Rc<str>>),
+}
+
+// A var without value (i.e. `null`), a boolean or a number are all interpreted as a string — the first one being empty.
+impl<'de> Deserialize<'de> for VarValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        fn as_scalar<E: serde::de::Error>(
+            value: serde_json::Value,
+        ) -> std::result::Result<Rc<str>, E> {
+            match value {
+                serde_json::Value::Null => Ok("".into()),
+                serde_json::Value::String(s) => Ok(s.into()),
+                serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+                    Ok(value.to_string().into())
+                }
+                serde_json::Value::Array(_) | serde_json::Value::Object(_) => Err(E::custom(
+                    format!("expected a value or a list of values, found: {}", value),
+                )),
+            }
+        }
+
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(values) => Ok(Self::List(
+                values
+                    .into_iter()
+                    .map(as_scalar)
+                    .collect::<std::result::Result<_, _>>()?,
+            )),
+            value => Ok(Self::Scalar(as_scalar(value)?)),
+        }
+    }
+}
+
+impl Display for VarValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VarValue::Scalar(value) => write!(f, "{}", value),
+            VarValue::List(values) => write!(f, "[{}]", values.join(", ")),
+        }
+    }
+}
+
+/// Returns the expression of the var `key`, as it is left in a rendered template when `key` holds a list of values.
+pub(crate) fn list_expression(key: &str) -> String {
+    format!("{{{{ {} }}}}", key)
+}
+
+/// Returns the [Handlebars] registry with which the templates are rendered.
+///
+/// The registry is in strict mode: rendering a template that references an undeclared var fails.
+pub(crate) fn new_registry() -> Handlebars<'static> {
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(true);
+
+    handlebars
+}
+
+/// Returns the names referenced in the provided `template`, or an empty set if it is not a valid template.
+///
+/// A name is referenced if it appears in an expression (e.g. `{{ BUILD }}`) or as a parameter of a helper, including
+/// in a sub-expression (e.g. `{{#if (eq ROUTE "filtered")}}`). The template is parsed once, it is not rendered.
+fn referenced_names(template: &str) -> HashSet<String> {
+    fn visit_template(template: &Template, names: &mut HashSet<String>) {
+        for element in &template.elements {
+            if let TemplateElement::Expression(helper)
+            | TemplateElement::HtmlExpression(helper)
+            | TemplateElement::HelperBlock(helper) = element
+            {
+                visit_helper(helper, names);
+            }
+        }
+    }
+
+    fn visit_helper(helper: &HelperTemplate, names: &mut HashSet<String>) {
+        // NOTE: Without parameters, the name is that of a var. Otherwise, it is the name of a helper.
+        if helper.params.is_empty() && helper.hash.is_empty() {
+            visit_parameter(&helper.name, names);
+        }
+
+        for parameter in helper.params.iter().chain(helper.hash.values()) {
+            visit_parameter(parameter, names);
+        }
+
+        for template in helper.template.iter().chain(helper.inverse.iter()) {
+            visit_template(template, names);
+        }
+    }
+
+    fn visit_parameter(parameter: &Parameter, names: &mut HashSet<String>) {
+        match parameter {
+            Parameter::Name(_) | Parameter::Path(_) => {
+                if let Some(name) = parameter.as_name() {
+                    names.insert(name.to_string());
+                }
+            }
+            Parameter::Subexpression(subexpression) => {
+                if let TemplateElement::Expression(helper) = subexpression.as_element() {
+                    visit_helper(helper, names);
+                }
+            }
+            Parameter::Literal(_) => {}
+        }
+    }
+
+    let mut names = HashSet::default();
+    if let Ok(template) = Template::compile(template) {
+        visit_template(&template, &mut names);
+    }
+
+    names
+}
+
+/// Returns the data with which a template is rendered: each var associated with the text that replaces it.
+///
+/// The expression of a var holding a list of values is left as is, the list being expanded once the rendered template
+/// is parsed (see [Vars]).
+pub(crate) fn template_data(vars: &HashMap<Rc<str>, VarValue>) -> HashMap<Rc<str>, Rc<str>> {
+    vars.iter()
+        .map(|(key, value)| match value {
+            VarValue::Scalar(value) => (key.clone(), value.clone()),
+            VarValue::List(_) => (key.clone(), list_expression(key).into()),
+        })
+        .collect()
+}
+
+impl<T: AsRef<str>> FromIterator<T> for VarValue {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::List(
+            iter.into_iter()
+                .map(|value| value.as_ref().into())
+                .collect(),
+        )
+    }
 }
 
 impl Deref for Vars {
@@ -75,15 +275,328 @@ impl Deref for Vars {
     }
 }
 
-impl IMergeOverwrite for Vars {
-    fn merge_overwrite(self, other: Self) -> Self {
-        let mut merged = (*other.vars).clone();
-        merged.extend((*self.vars).clone());
+/// Displays one `KEY=VALUE` pair per line, sorted by key.
+impl Display for Vars {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = self.values();
+        let mut vars = values.iter().collect::<Vec<_>>();
+        vars.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        for (key, value) in vars {
+            writeln!(f, "{}={}", key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Vars {
+    /// Returns the list of values held by the var `key`, or `None` if there is no such var or if it holds a single
+    /// value.
+    pub fn get_list(&self, key: &str) -> Option<&[Rc<str>]> {
+        self.lists.get(key).map(Vec::as_slice)
+    }
+
+    /// Returns the vars holding a list of values, indexed by their [expression](list_expression).
+    pub(crate) fn list_expressions(&self) -> HashMap<String, &[Rc<str>]> {
+        self.lists
+            .iter()
+            .map(|(key, values)| (list_expression(key), values.as_slice()))
+            .collect()
+    }
+
+    /// Returns all the vars, the ones holding a single value and the ones holding a list of values.
+    pub(crate) fn values(&self) -> HashMap<Rc<str>, VarValue> {
+        self.vars
+            .iter()
+            .map(|(key, value)| (key.clone(), VarValue::Scalar(value.clone())))
+            .chain(
+                self.lists
+                    .iter()
+                    .map(|(key, values)| (key.clone(), VarValue::List(values.clone()))),
+            )
+            .collect()
+    }
+
+    /// Creates [Vars] from vars holding either a single value or a list of values.
+    fn from_values(values: impl IntoIterator<Item = (Rc<str>, VarValue)>) -> Self {
+        let mut vars = HashMap::default();
+        let mut lists = HashMap::default();
+        for (key, value) in values {
+            match value {
+                VarValue::Scalar(value) => {
+                    vars.insert(key, value);
+                }
+                VarValue::List(values) => {
+                    lists.insert(key, values);
+                }
+            }
+        }
 
         Self {
-            vars: Rc::new(merged),
+            vars: Rc::new(vars),
+            lists: Rc::new(lists),
         }
     }
+
+    /// Attempts to create [Vars] from the `.env` file located at `path`.
+    ///
+    /// Each `KEY=VALUE` pair of the file is a var holding a single value. Comments (lines starting with `#`), quoted
+    /// values and multi-line (double-quoted) values are supported.
+    ///
+    /// The [Vars] obtained can be composed with the ones declared in a descriptor through
+    /// [merge_overwrite](IMergeOverwrite::merge_overwrite()), as any other [Vars].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the file could not be read or if it is not a valid `.env` file.
+    pub fn from_dotenv(path: impl AsRef<Path>) -> Result<Vars> {
+        let path = path.as_ref();
+        let vars = dotenvy::from_path_iter(path)
+            .context(format!("Failed to open .env file:\n{}", path.display()))?
+            .map(|item| item.map(|(key, value)| (key.into(), value.into())))
+            .collect::<std::result::Result<HashMap<_, _>, _>>()
+            .context(format!("Failed to parse .env file:\n{}", path.display()))?;
+
+        Ok(Vars {
+            vars: Rc::new(vars),
+            lists: Rc::default(),
+        })
+    }
+
+    /// Returns the keys of the [Vars] that are not referenced in the provided `template`, sorted alphabetically.
+    ///
+    /// A key is considered unused if neither the `template` nor another var references it.
+    ///
+    /// If the `template` cannot be rendered with all the [Vars] then no key is returned: the template itself is
+    /// invalid and reporting unused keys would be meaningless.
+    ///
+    /// # Limitations
+    ///
+    /// The keys returned are only *likely* to be unused, they should be reported as warnings and not as errors. This
+    /// method produces false positives, i.e. keys that are reported as unused while they are used, when:
+    /// - a key is only used in a nested descriptor (e.g. a composite operator declared in another file): only the
+    ///   provided `template` is considered,
+    /// - a key is only referenced through a string literal, for instance `{{ lookup this "KEY" }}`: only the names and
+    ///   paths of the expressions are considered.
+    pub fn unused_in(&self, template: &str) -> Vec<Rc<str>> {
+        let values = self.values();
+        if new_registry()
+            .render_template(template, &template_data(&values))
+            .is_err()
+        {
+            return Vec::default();
+        }
+
+        let referenced_in_template = referenced_names(template);
+        let referenced_by_vars = values
+            .values()
+            .flat_map(|value| references_in(&values, value))
+            .collect::<HashSet<_>>();
+
+        let mut unused = values
+            .keys()
+            .filter(|&key| {
+                !referenced_by_vars.contains(key) && !referenced_in_template.contains(key.as_ref())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        unused.sort();
+
+        unused
+    }
+
+    /// Returns the [VarsDiff] between `self` and `other`: what should be applied to `self` to obtain `other`.
+    pub fn diff(&self, other: &Vars) -> VarsDiff {
+        let mut diff = VarsDiff::default();
+        let (values, other_values) = (self.values(), other.values());
+
+        for (key, value) in values.iter() {
+            match other_values.get(key) {
+                None => {
+                    diff.removed.insert(key.clone(), value.clone());
+                }
+                Some(other_value) if other_value != value => {
+                    diff.changed
+                        .insert(key.clone(), (value.clone(), other_value.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+
+        for (key, value) in other_values.iter() {
+            if !values.contains_key(key) {
+                diff.added.insert(key.clone(), value.clone());
+            }
+        }
+
+        diff
+    }
+
+    /// Returns a copy of these [Vars] where the references to other vars, in their values, are resolved.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - the vars reference each other in a cycle,
+    /// - a var references a var that is not declared.
+    pub fn try_resolve(&self) -> Result<Vars> {
+        let mut handlebars = new_registry();
+        // NOTE: The values are escaped when the descriptor is rendered. Escaping them here as well would escape the
+        // values of the referenced vars twice.
+        handlebars.register_escape_fn(handlebars::no_escape);
+        let values = self.values();
+
+        let mut keys = values.keys().collect::<Vec<_>>();
+        // NOTE: Sorting the keys makes the reported cycle, if any, deterministic.
+        keys.sort();
+
+        let mut resolved = HashMap::with_capacity(values.len());
+        for key in keys {
+            try_resolve_var(
+                &handlebars,
+                &values,
+                key,
+                &mut resolved,
+                &mut Vec::default(),
+            )?;
+        }
+
+        Ok(Vars::from_values(resolved))
+    }
+}
+
+/// Resolves the var `key` of `values`, after having resolved all the vars it references, and adds it to `resolved`.
+///
+/// `path` holds the vars whose resolution is ongoing: finding `key` in it means that there is a cycle.
+fn try_resolve_var(
+    handlebars: &Handlebars,
+    values: &HashMap<Rc<str>, VarValue>,
+    key: &Rc<str>,
+    resolved: &mut HashMap<Rc<str>, VarValue>,
+    path: &mut Vec<Rc<str>>,
+) -> Result<()> {
+    if resolved.contains_key(key) {
+        return Ok(());
+    }
+
+    if let Some(position) = path.iter().position(|var| var == key) {
+        bail!(
+            "The vars reference each other in a cycle: {} -> {}",
+            path[position..].join(" -> "),
+            key
+        );
+    }
+
+    // NOTE: An undeclared var will make the rendering of the var that references it fail, with a clearer error.
+    let Some(value) = values.get(key) else {
+        return Ok(());
+    };
+
+    path.push(key.clone());
+    for reference in references_in(values, value) {
+        try_resolve_var(handlebars, values, reference, resolved, path)?;
+    }
+    path.pop();
+
+    let data = template_data(resolved);
+    let render = |template: &Rc<str>| -> Result<Rc<str>> {
+        if !template.contains("{{") {
+            return Ok(template.clone());
+        }
+
+        handlebars
+            .render_template(template, &data)
+            .map(Into::into)
+            .context(format!("Failed to expand var < {} >", key))
+    };
+
+    let value = match value {
+        VarValue::Scalar(template) => VarValue::Scalar(render(template)?),
+        VarValue::List(templates) => {
+            VarValue::List(templates.iter().map(render).collect::<Result<_>>()?)
+        }
+    };
+    resolved.insert(key.clone(), value);
+
+    Ok(())
+}
+
+/// Returns the keys of the vars, among `values`, referenced in the provided `value`.
+fn references_in<'a>(values: &'a HashMap<Rc<str>, VarValue>, value: &VarValue) -> Vec<&'a Rc<str>> {
+    let templates = match value {
+        VarValue::Scalar(template) => std::slice::from_ref(template),
+        VarValue::List(templates) => templates.as_slice(),
+    };
+
+    let names = templates
+        .iter()
+        .filter(|template| template.contains("{{"))
+        .flat_map(|template| referenced_names(template))
+        .collect::<HashSet<_>>();
+
+    values
+        .keys()
+        .filter(|&key| names.contains(key.as_ref()))
+        .collect()
+}
+
+/// The differences between two [Vars], see [Vars::diff].
+///
+/// Its [Display] implementation presents the differences sorted by key, in a format close to the one of `git diff`:
+///
+/// ```text
+/// - REMOVED: value
+/// + ADDED: value
+/// - CHANGED: old
+/// + CHANGED: new
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VarsDiff {
+    pub added: HashMap<Rc<str>, VarValue>,
+    pub removed: HashMap<Rc<str>, VarValue>,
+    pub changed: HashMap<Rc<str>, (VarValue, VarValue)>,
+}
+
+impl VarsDiff {
+    /// Returns `true` if there is no difference.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Display for VarsDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut keys = self
+            .added
+            .keys()
+            .chain(self.removed.keys())
+            .chain(self.changed.keys())
+            .collect::<Vec<_>>();
+        keys.sort();
+
+        for key in keys {
+            if let Some(value) = self.removed.get(key) {
+                writeln!(f, "- {}: {}", key, value)?;
+            } else if let Some(value) = self.added.get(key) {
+                writeln!(f, "+ {}: {}", key, value)?;
+            } else if let Some((old, new)) = self.changed.get(key) {
+                writeln!(f, "- {}: {}", key, old)?;
+                writeln!(f, "+ {}: {}", key, new)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl IMergeOverwrite for Vars {
+    fn merge_overwrite(self, other: Self) -> Self {
+        let mut merged = other.values();
+        merged.extend(self.values());
+
+        Vars::from_values(merged)
+    }
 }
 
 impl<T: AsRef<str>, U: AsRef<str>, const N: usize> From<[(T, U); N]> for Vars {
@@ -95,6 +608,7 @@ impl<T: AsRef<str>, U: AsRef<str>, const N: usize> From<[(T, U); N]> for Vars {
                     .map(|(k, v)| (k.as_ref().into(), v.as_ref().into()))
                     .collect::<HashMap<Rc<str>, Rc<str>>>(),
             ),
+            lists: Rc::default(),
         }
     }
 }
@@ -108,10 +622,17 @@ impl<T: AsRef<str>, U: AsRef<str>> From<Vec<(T, U)>> for Vars {
                     .map(|(k, v)| (k.as_ref().into(), v.as_ref().into()))
                     .collect::<HashMap<Rc<str>, Rc<str>>>(),
             ),
+            lists: Rc::default(),
         }
     }
 }
 
+impl<T: AsRef<str>> From<Vec<(T, VarValue)>> for Vars {
+    fn from(value: Vec<(T, VarValue)>) -> Self {
+        Vars::from_values(value.into_iter().map(|(k, v)| (k.as_ref().into(), v)))
+    }
+}
+
 /// Parse a single [Var](Vars) from a string of the format "KEY=VALUE".
 ///
 /// Note that if several "=" characters are present in the string, only the first one will be considered as a separator
@@ -134,3 +655,359 @@ where
         .ok_or_else(|| format!("invalid KEY=value: no `=` found in `{s}`"))?;
     Ok((s[..pos].parse()?, s[pos + 1..].parse()?))
 }
+
+/// Parse a single [Var](Vars) holding a list of values from a string of the format "KEY=VALUE_1,VALUE_2,VALUE_3".
+///
+/// Note that, similar to [parse_vars], only the first "=" character is considered as a separator. The VALUE part is
+/// then split on every "," character. A VALUE part that is empty produces an empty list.
+///
+/// # Errors
+///
+/// This function will return an error if no "=" character was found or if one of the values could not be parsed.
+pub fn parse_vars_list<T, U>(
+    s: &str,
+) -> std::result::Result<(T, Vec<U>), Box<dyn Error + Send + Sync + 'static>>
+where
+    T: std::str::FromStr,
+    T::Err: Error + Send + Sync + 'static,
+    U: std::str::FromStr,
+    U::Err: Error + Send + Sync + 'static,
+{
+    let pos = s
+        .find('=')
+        .ok_or_else(|| format!("invalid KEY=value: no `=` found in `{s}`"))?;
+
+    let values = &s[pos + 1..];
+    if values.is_empty() {
+        return Ok((s[..pos].parse()?, Vec::default()));
+    }
+
+    Ok((
+        s[..pos].parse()?,
+        values
+            .split(',')
+            .map(|value| value.parse())
+            .collect::<std::result::Result<Vec<_>, _>>()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{try_parse_from_file, try_parse_from_str, DescriptorFormat};
+    use std::path::{Path, PathBuf};
+
+    /// A file, with a unique name, in the temporary directory. It is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(extension: &str, content: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "zenoh-flow-commons-{}.{}",
+                uuid::Uuid::new_v4(),
+                extension
+            ));
+            std::fs::write(&path, content).expect("Failed to write temporary file");
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_parse_vars_list() {
+        let (key, values) =
+            parse_vars_list::<String, String>("PORTS=out-0,out-1,out-2").expect("Failed to parse");
+        assert_eq!("PORTS", key);
+        assert_eq!(vec!["out-0", "out-1", "out-2"], values);
+
+        let (_, values) = parse_vars_list::<String, String>("PORTS=").expect("Failed to parse");
+        assert!(values.is_empty());
+
+        let (_, values) = parse_vars_list::<String, u64>("ANSWERS=4,2").expect("Failed to parse");
+        assert_eq!(vec![4, 2], values);
+
+        assert!(parse_vars_list::<String, String>("PORTS").is_err());
+        assert!(parse_vars_list::<String, u64>("ANSWERS=4,two").is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = Vars::from([("BUILD", "debug"), ("DLL_EXT", "so"), ("UNCHANGED", "same")]);
+        let new = Vars::from(vec![
+            ("BUILD", VarValue::Scalar("release".into())),
+            ("UNCHANGED", VarValue::Scalar("same".into())),
+            ("PORTS", ["out-0", "out-1"].into_iter().collect()),
+        ]);
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            VarsDiff {
+                added: HashMap::from([(
+                    "PORTS".into(),
+                    VarValue::List(vec!["out-0".into(), "out-1".into()])
+                )]),
+                removed: HashMap::from([("DLL_EXT".into(), VarValue::Scalar("so".into()))]),
+                changed: HashMap::from([(
+                    "BUILD".into(),
+                    (
+                        VarValue::Scalar("debug".into()),
+                        VarValue::Scalar("release".into())
+                    )
+                )]),
+            },
+            diff
+        );
+
+        assert_eq!(
+            "- BUILD: debug\n+ BUILD: release\n- DLL_EXT: so\n+ PORTS: [out-0, out-1]\n",
+            diff.to_string()
+        );
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let vars = Vars::from(vec![
+            ("DLL_EXT", VarValue::Scalar("so".into())),
+            ("BUILD", VarValue::Scalar("debug".into())),
+            ("PORTS", ["out-0", "out-1"].into_iter().collect()),
+        ]);
+
+        assert_eq!(
+            "BUILD=debug\nDLL_EXT=so\nPORTS=[out-0, out-1]\n",
+            vars.to_string()
+        );
+        assert_eq!("", Vars::default().to_string());
+    }
+
+    #[test]
+    fn test_from_dotenv() {
+        let file = TempFile::new(
+            "env",
+            r#"
+# The build profile.
+BUILD=release
+DLL_EXT='so'
+MESSAGE="first line
+second line"
+"#,
+        );
+
+        let vars = Vars::from_dotenv(file.path()).expect("Failed to parse .env file");
+        assert_eq!(3, vars.len());
+        assert_eq!(Some(&Rc::from("release")), vars.get("BUILD"));
+        assert_eq!(Some(&Rc::from("so")), vars.get("DLL_EXT"));
+        assert_eq!(
+            Some(&Rc::from("first line\nsecond line")),
+            vars.get("MESSAGE")
+        );
+
+        let merged = Vars::from([("BUILD", "debug")]).merge_overwrite(vars);
+        assert_eq!(Some(&Rc::from("debug")), merged.get("BUILD"));
+
+        let missing = file.path().with_extension("missing.env");
+        assert!(Vars::from_dotenv(missing).is_err());
+    }
+
+    #[test]
+    fn test_expand_list() {
+        #[derive(Deserialize)]
+        struct Node {
+            outputs: Vec<String>,
+        }
+
+        let file = TempFile::new(
+            "yaml",
+            r#"
+vars:
+  PORTS: [ "default" ]
+
+outputs: "{{ PORTS }}"
+"#,
+        );
+
+        let (key, values) =
+            parse_vars_list::<String, String>("PORTS=out-0,out-1").expect("Failed to parse");
+        let (node, vars) = try_parse_from_file::<Node>(
+            file.path(),
+            Vars::from(vec![(key, values.into_iter().collect::<VarValue>())]),
+        )
+        .expect("Failed to parse Node");
+        assert_eq!(vec!["out-0", "out-1"], node.outputs);
+        assert_eq!(
+            Some([Rc::from("out-0"), Rc::from("out-1")].as_slice()),
+            vars.get_list("PORTS")
+        );
+        assert!(vars.get("PORTS").is_none());
+    }
+
+    #[test]
+    fn test_expand_list_as_json() {
+        #[derive(Deserialize)]
+        struct Node {
+            library: String,
+            outputs: Vec<String>,
+        }
+
+        let ports = ["out, 0", "out: 1", "#out-2", r#""out-3""#, "out'4"];
+        let vars = Vars::from(vec![("PORTS", ports.into_iter().collect::<VarValue>())]);
+
+        let file = TempFile::new(
+            "json",
+            r#"
+{
+  "vars": {
+    "DLL_EXT": "so",
+    "PORTS": [ "default" ]
+  },
+  "library": "file:///zenoh-flow/libnode.{{ DLL_EXT }}",
+  "outputs": "{{ PORTS }}"
+}
+"#,
+        );
+
+        let (node, _) =
+            try_parse_from_file::<Node>(file.path(), vars.clone()).expect("Failed to parse Node");
+        assert_eq!("file:///zenoh-flow/libnode.so", node.library);
+        assert_eq!(ports.to_vec(), node.outputs);
+
+        let (node, _) = try_parse_from_str::<Node>(
+            r#"
+library: file:///zenoh-flow/libnode.so
+outputs: "{{ PORTS }}"
+"#,
+            DescriptorFormat::Yaml,
+            vars.clone(),
+        )
+        .expect("Failed to parse Node");
+        assert_eq!(ports.to_vec(), node.outputs);
+
+        // A list cannot be part of a string.
+        assert!(try_parse_from_str::<Node>(
+            r#"
+library: "file:///zenoh-flow/{{ PORTS }}.so"
+outputs: "{{ PORTS }}"
+"#,
+            DescriptorFormat::Yaml,
+            vars,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        #[derive(Deserialize)]
+        struct Node {
+            library: String,
+        }
+
+        let descriptor = r#"
+vars:
+  BUILD: debug
+  LIB_DIR: "/zenoh-flow/target/{{ BUILD }}"
+  LIBRARY: "file://{{ LIB_DIR }}/libnode.so"
+
+library: "{{ LIBRARY }}"
+"#;
+
+        let (node, vars) =
+            try_parse_from_str::<Node>(descriptor, DescriptorFormat::Yaml, Vars::default())
+                .expect("Failed to parse Node");
+        assert_eq!("file:///zenoh-flow/target/debug/libnode.so", node.library);
+        assert_eq!(
+            Some(&Rc::from("/zenoh-flow/target/debug")),
+            vars.get("LIB_DIR")
+        );
+
+        // The overwritten value is the one used to compose the others.
+        let (node, _) = try_parse_from_str::<Node>(
+            descriptor,
+            DescriptorFormat::Yaml,
+            Vars::from([("BUILD", "release")]),
+        )
+        .expect("Failed to parse Node");
+        assert_eq!("file:///zenoh-flow/target/release/libnode.so", node.library);
+
+        // `BUILD` is only used by another var: it is not unused.
+        assert!(
+            Vars::from([("BUILD", "debug"), ("LIB_DIR", "{{ BUILD }}/lib")])
+                .unused_in("library: {{ LIB_DIR }}")
+                .is_empty()
+        );
+
+        let error = Vars::from([("A", "{{ B }}"), ("B", "{{ C }}/b"), ("C", "{{ A }}")])
+            .try_resolve()
+            .expect_err("Cycle not detected");
+        assert_eq!(
+            "The vars reference each other in a cycle: A -> B -> C -> A",
+            error.to_string()
+        );
+
+        assert!(Vars::from([("A", "{{ A }}/a")]).try_resolve().is_err());
+        assert!(Vars::from([("A", "{{ MISSING }}")]).try_resolve().is_err());
+    }
+
+    #[test]
+    fn test_resolve_escape_once() {
+        #[derive(Deserialize)]
+        struct Node {
+            query: String,
+            uri: String,
+        }
+
+        let vars = Vars::from([
+            ("QUERY", "build=release&ext=<so>"),
+            ("URI", "https://zenoh.io/'node'?{{ QUERY }}"),
+        ])
+        .try_resolve()
+        .expect("Failed to resolve vars");
+        assert_eq!(
+            Some(&Rc::from("https://zenoh.io/'node'?build=release&ext=<so>")),
+            vars.get("URI")
+        );
+
+        // The values are escaped once when the descriptor is rendered, whether they are composed or not.
+        let (node, _) = try_parse_from_str::<Node>(
+            r#"
+query: "{{ QUERY }}"
+uri: "{{ URI }}"
+"#,
+            DescriptorFormat::Yaml,
+            vars,
+        )
+        .expect("Failed to parse Node");
+        let query = "build&#x3D;release&amp;ext&#x3D;&lt;so&gt;";
+        assert_eq!(query, node.query);
+        assert_eq!(
+            format!("https://zenoh.io/&#x27;node&#x27;?{query}"),
+            node.uri
+        );
+    }
+
+    #[test]
+    fn test_unused_in_helpers() {
+        let vars = Vars::from([
+            ("ROUTE", "filtered"),
+            ("FILTER", "{{#if (eq ROUTE \"filtered\")}}on{{/if}}"),
+            ("UNUSED", "unused"),
+        ]);
+
+        assert_eq!(
+            vec![Rc::from("UNUSED")],
+            vars.unused_in("filter: {{ FILTER }}")
+        );
+        assert_eq!(
+            vec![Rc::from("FILTER"), Rc::from("UNUSED")],
+            vars.unused_in("route: {{#if ROUTE}}{{ ROUTE }}{{/if}}")
+        );
+    }
+}
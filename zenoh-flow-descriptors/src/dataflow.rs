@@ -241,6 +241,13 @@ pub struct DataFlowDescriptor {
     pub(crate) id: Option<InstanceId>,
     /// A human-readable description of the data flow.
     pub(crate) name: Arc<str>,
+    /// *(optional)* A prefix added to the Zenoh key expressions generated to connect nodes running on different
+    /// Zenoh-Flow runtimes.
+    ///
+    /// Providing a namespace allows several deployments sharing the same Zenoh infrastructure to reuse the same data
+    /// flow `id` without their communications interfering.
+    #[serde(default)]
+    pub(crate) namespace: Option<Arc<str>>,
     /// *(optional)* Pairs of `(key, value)` that are transmitted to the nodes at their creation.
     ///
     /// Each node can specify its own configuration. See the documentation of [Configuration] to see how multiple
@@ -265,6 +272,13 @@ pub struct DataFlowDescriptor {
     /// a mapping to the Zenoh-Flow runtime that was requested to instantiate the data flow.
     #[serde(default)]
     pub(crate) mapping: HashMap<RuntimeId, HashSet<NodeId>>,
+    /// *(optional)* Whether the links are allowed to form cycles, defaults to `false`.
+    ///
+    /// A cycle is a legitimate construct (e.g. a feedback loop in a controller) but it is also an easy way to introduce
+    /// a deadlock: if all the nodes of a cycle wait for data from their upstream node, none will ever produce any.
+    /// Cycles must thus be explicitly allowed.
+    #[serde(default, alias = "allow-cycles")]
+    pub(crate) allow_cycles: bool,
 }
 
 #[cfg(test)]
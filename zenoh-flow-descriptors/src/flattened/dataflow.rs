@@ -16,16 +16,18 @@ use crate::{
     DataFlowDescriptor, FlattenedOperatorDescriptor, FlattenedSinkDescriptor,
     FlattenedSourceDescriptor, LinkDescriptor,
 };
-use anyhow::Context;
+use anyhow::{bail, Context};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
     fmt::Display,
     sync::Arc,
 };
-use zenoh_flow_commons::{Configuration, InstanceId, NodeId, Result, RuntimeId, Vars};
+use zenoh_flow_commons::{
+    Configuration, DescriptorFormat, InstanceId, NodeId, Result, RuntimeId, Vars,
+};
 
-use super::validator::Validator;
+use super::validator::{display_cycle, find_cycle, Validator};
 
 /// A `FlattenedDataFlowDescriptor` is a self-contained description of a data flow.
 ///
@@ -50,6 +52,10 @@ pub struct FlattenedDataFlowDescriptor {
     pub id: Option<InstanceId>,
     /// A human-readable description of the data flow.
     pub name: Arc<str>,
+    /// *(optional)* A prefix added to the Zenoh key expressions generated to connect nodes running on different
+    /// Zenoh-Flow runtimes.
+    #[serde(default)]
+    pub namespace: Option<Arc<str>>,
     /// A non-empty list of Sources.
     pub sources: Vec<FlattenedSourceDescriptor>,
     /// A list of Operators.
@@ -65,6 +71,9 @@ pub struct FlattenedDataFlowDescriptor {
     /// a mapping to the Zenoh-Flow runtime that instantiates the data flow.
     #[serde(default)]
     pub mapping: HashMap<RuntimeId, HashSet<NodeId>>,
+    /// Whether the links are allowed to form cycles.
+    #[serde(default, alias = "allow-cycles")]
+    pub allow_cycles: bool,
 }
 
 impl Display for FlattenedDataFlowDescriptor {
@@ -98,16 +107,26 @@ impl FlattenedDataFlowDescriptor {
     /// - all nodes, regardless of their type, have a different identifier,
     /// - no node has two inputs or two outputs with the same identifier,
     /// - all outputs are connected to at least one input,
-    /// - all inputs are connected to at least one output.
+    /// - all inputs are connected to at least one output,
+    /// - the links do not form a cycle, unless cycles are explicitly allowed.
+    ///
+    /// # Disabled Operators
+    ///
+    /// An Operator that is disabled (i.e. its `enabled` field is set to `false`) is removed from the data flow and
+    /// bypassed: its incoming link is connected to the input(s) its outgoing link(s) pointed to. A disabled Operator
+    /// must thus have a single incoming link and all its outgoing links must originate from the same output.
     ///
     /// # Errors
     ///
     /// A flattening operation can fail for multiple reasons:
+    /// - A disabled Operator cannot be bypassed.
     /// - The flattening of an Operator failed.
     /// - The flattening of a Source failed.
     /// - The flattening of a Sink failed.
     /// - The flattened data flow is not valid.
     pub fn try_flatten(mut data_flow: DataFlowDescriptor, vars: Vars) -> Result<Self> {
+        try_bypass_disabled_operators(&mut data_flow)?;
+
         let mut flattened_operators = Vec::with_capacity(data_flow.operators.len());
         for operator_desc in data_flow.operators {
             let operator_id = operator_desc.id.clone();
@@ -160,11 +179,13 @@ impl FlattenedDataFlowDescriptor {
         let flattened_data_flow = Self {
             id: data_flow.id,
             name: data_flow.name,
+            namespace: data_flow.namespace,
             sources,
             operators: flattened_operators,
             sinks,
             links: data_flow.links,
             mapping: data_flow.mapping,
+            allow_cycles: data_flow.allow_cycles,
         };
 
         Validator::validate(&flattened_data_flow)
@@ -173,6 +194,43 @@ impl FlattenedDataFlowDescriptor {
         Ok(flattened_data_flow)
     }
 
+    /// Attempts to parse a [DataFlowDescriptor] from the provided `content`, written in the provided `format`, and to
+    /// flatten it.
+    ///
+    /// The provided [Vars] overwrite the `vars` section of the descriptor. This is a convenience function that chains
+    /// [try_parse_from_str](zenoh_flow_commons::try_parse_from_str()) and [try_flatten](Self::try_flatten()).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the descriptor could not be parsed or if its flattening failed.
+    pub fn try_parse_from_str(content: &str, format: DescriptorFormat, vars: Vars) -> Result<Self> {
+        let (data_flow, vars) =
+            zenoh_flow_commons::try_parse_from_str::<DataFlowDescriptor>(content, format, vars)?;
+        Self::try_flatten(data_flow, vars)
+    }
+
+    /// Attempts to parse and flatten a [DataFlowDescriptor] from raw bytes, written in the provided `format`.
+    ///
+    /// This method behaves as [try_parse_from_str](Self::try_parse_from_str()), the bytes being first interpreted as
+    /// UTF-8. It is intended as an entry point for fuzzing: any input, however malformed, results in an error and not
+    /// in a panic.
+    ///
+    /// Note that the file system is only accessed if the descriptor references other descriptors (e.g. a composite
+    /// Operator declared in a separate file).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the bytes are not valid UTF-8, if the descriptor could not be parsed or if
+    /// its flattening failed.
+    pub fn try_parse_from_bytes(
+        bytes: &[u8],
+        format: DescriptorFormat,
+        vars: Vars,
+    ) -> Result<Self> {
+        let content = std::str::from_utf8(bytes).context("The descriptor is not valid UTF-8")?;
+        Self::try_parse_from_str(content, format, vars)
+    }
+
     /// Returns the unique identifier of the Zenoh-Flow runtime on which the node is configured to run.
     ///
     /// If there is no mapping entry for this specific node, `None` is returned.
@@ -185,6 +243,225 @@ impl FlattenedDataFlowDescriptor {
 
         None
     }
+
+    /// Attempts to sort the nodes of this data flow in topological order: a node always comes after the nodes that
+    /// send it data.
+    ///
+    /// Nodes that do not depend on each other are sorted following their declaration: Sources first, then Operators and
+    /// finally Sinks. The returned order is thus deterministic.
+    ///
+    /// This order can be used to start the nodes (in reverse, such that a node is started after its downstream nodes
+    /// and no data is lost) or to check that the data flow is a Directed Acyclic Graph.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the links form a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>> {
+        let nodes = self
+            .sources
+            .iter()
+            .map(|source| &source.id)
+            .chain(self.operators.iter().map(|operator| &operator.id))
+            .chain(self.sinks.iter().map(|sink| &sink.id))
+            .collect::<Vec<_>>();
+
+        let mut in_degrees = nodes
+            .iter()
+            .map(|&node| (node, 0usize))
+            .collect::<HashMap<_, _>>();
+        let mut successors: HashMap<&NodeId, Vec<&NodeId>> = HashMap::default();
+        for link in self.links.iter() {
+            successors
+                .entry(&link.from.node)
+                .or_default()
+                .push(&link.to.node);
+            *in_degrees.entry(&link.to.node).or_default() += 1;
+        }
+
+        let mut sorted = Vec::with_capacity(nodes.len());
+        let mut ready = nodes
+            .iter()
+            .filter(|&node| in_degrees.get(node) == Some(&0))
+            .copied()
+            .collect::<VecDeque<_>>();
+
+        while let Some(node) = ready.pop_front() {
+            sorted.push(node.clone());
+
+            for &successor in successors.get(node).into_iter().flatten() {
+                if let Some(in_degree) = in_degrees.get_mut(successor) {
+                    *in_degree -= 1;
+                    if *in_degree == 0 {
+                        ready.push_back(successor);
+                    }
+                }
+            }
+        }
+
+        if sorted.len() != nodes.len() {
+            match find_cycle(self) {
+                Some(cycle) => bail!(
+                    "The data flow is not a Directed Acyclic Graph, the links form the cycle: {}",
+                    display_cycle(&cycle)
+                ),
+                None => bail!("The data flow is not a Directed Acyclic Graph"),
+            }
+        }
+
+        Ok(sorted)
+    }
+
+    /// Returns `true` if no link of this data flow connects two nodes of `set_a` or two nodes of `set_b`.
+    ///
+    /// This allows verifying that a deployment is partitioned, e.g. that all links go from a group of "compute" nodes
+    /// to a group of "output" nodes. Links involving nodes that belong to neither set are not considered.
+    pub fn is_bipartite_between(&self, set_a: &[NodeId], set_b: &[NodeId]) -> bool {
+        let set_a = set_a.iter().collect::<HashSet<_>>();
+        let set_b = set_b.iter().collect::<HashSet<_>>();
+
+        !self.links.iter().any(|link| {
+            (set_a.contains(&link.from.node) && set_a.contains(&link.to.node))
+                || (set_b.contains(&link.from.node) && set_b.contains(&link.to.node))
+        })
+    }
+
+    /// Attempts to remove the node with the provided identifier, along with the links it is part of and its mapping
+    /// entry.
+    ///
+    /// Unless `force` is set, the data flow is validated after the removal (see
+    /// [try_flatten](Self::try_flatten()) for the validity rules). If it is no longer valid, e.g. because an input of a
+    /// downstream node is left unconnected, the data flow is left untouched and an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - there is no node with the provided identifier,
+    /// - `force` is not set and the data flow would no longer be valid.
+    pub fn try_remove_node(&mut self, node: &NodeId, force: bool) -> Result<()> {
+        let mut data_flow = self.clone();
+
+        let number_nodes =
+            data_flow.sources.len() + data_flow.operators.len() + data_flow.sinks.len();
+        data_flow.sources.retain(|source| &source.id != node);
+        data_flow.operators.retain(|operator| &operator.id != node);
+        data_flow.sinks.retain(|sink| &sink.id != node);
+        if number_nodes
+            == data_flow.sources.len() + data_flow.operators.len() + data_flow.sinks.len()
+        {
+            bail!("Found no node < {} > in the data flow", node);
+        }
+
+        data_flow
+            .links
+            .retain(|link| &link.from.node != node && &link.to.node != node);
+        remove_from_mapping(&mut data_flow.mapping, node);
+
+        if !force {
+            Validator::validate(&data_flow).context(format!(
+                "Removing the node < {} > would leave the data flow in an invalid state",
+                node
+            ))?;
+        }
+
+        *self = data_flow;
+        Ok(())
+    }
+
+    /// Attempts to remove the provided link.
+    ///
+    /// Unless `force` is set, the data flow is validated after the removal. Note that, as every input must be connected,
+    /// removing a link always leaves its input unconnected: `force` is required until another link is added to that
+    /// input.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - the link does not exist,
+    /// - `force` is not set and the data flow would no longer be valid.
+    pub fn try_remove_link(&mut self, link: &LinkDescriptor, force: bool) -> Result<()> {
+        let mut data_flow = self.clone();
+
+        let number_links = data_flow.links.len();
+        data_flow.links.retain(|l| l != link);
+        if number_links == data_flow.links.len() {
+            bail!("Found no link in the data flow matching:\n{}", link);
+        }
+
+        if !force {
+            Validator::validate(&data_flow).context(format!(
+                "Removing the following link would leave the data flow in an invalid state:\n{}",
+                link
+            ))?;
+        }
+
+        *self = data_flow;
+        Ok(())
+    }
+}
+
+/// Removes the disabled Operators from the data flow, connecting their incoming link to the inputs their outgoing links
+/// pointed to.
+///
+/// # Errors
+///
+/// This function will return an error if a disabled Operator does not have exactly one incoming link, or if its outgoing
+/// links do not originate from the same output (or if it has none).
+fn try_bypass_disabled_operators(data_flow: &mut DataFlowDescriptor) -> Result<()> {
+    let disabled_operators = data_flow
+        .operators
+        .iter()
+        .filter(|&operator| !operator.enabled)
+        .map(|operator| operator.id.clone())
+        .collect::<Vec<_>>();
+
+    for operator_id in disabled_operators {
+        let (incoming, links): (Vec<_>, Vec<_>) = data_flow
+            .links
+            .drain(..)
+            .partition(|link| link.to.node == operator_id);
+        let (outgoing, mut links): (Vec<_>, Vec<_>) = links
+            .into_iter()
+            .partition(|link| link.from.node == operator_id);
+
+        let incoming = match incoming.as_slice() {
+            [incoming] => incoming,
+            _ => bail!(
+                "The disabled Operator < {} > cannot be bypassed: it must have exactly one incoming link, found {}",
+                operator_id,
+                incoming.len()
+            ),
+        };
+
+        match outgoing.first() {
+            Some(first) if outgoing.iter().all(|link| link.from == first.from) => {}
+            _ => bail!(
+                "The disabled Operator < {} > cannot be bypassed: all its outgoing links must originate from the same \
+output",
+                operator_id
+            ),
+        }
+
+        links.extend(outgoing.into_iter().map(|outgoing| LinkDescriptor {
+            from: incoming.from.clone(),
+            ..outgoing
+        }));
+        data_flow.links = links;
+
+        data_flow
+            .operators
+            .retain(|operator| operator.id != operator_id);
+        remove_from_mapping(&mut data_flow.mapping, &operator_id);
+    }
+
+    Ok(())
+}
+
+/// Removes the node from the mapping, along with the entries of the runtimes that no longer have a node assigned.
+fn remove_from_mapping(mapping: &mut HashMap<RuntimeId, HashSet<NodeId>>, node: &NodeId) {
+    mapping.values_mut().for_each(|nodes| {
+        nodes.remove(node);
+    });
+    mapping.retain(|_, nodes| !nodes.is_empty());
 }
 
 #[cfg(test)]
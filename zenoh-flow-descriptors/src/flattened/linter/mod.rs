@@ -0,0 +1,128 @@
+//
+// Copyright (c) 2021 - 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use crate::flattened::validator::{display_cycle, find_cycle};
+use crate::FlattenedDataFlowDescriptor;
+use anyhow::Context;
+use std::fmt::Display;
+use std::rc::Rc;
+use zenoh_flow_commons::{DescriptorFormat, IMergeOverwrite, NodeId, Result, Vars};
+
+/// A `LintWarning` signals a non-fatal issue in a data flow descriptor.
+///
+/// Contrary to the validation performed when [flattening](FlattenedDataFlowDescriptor::try_flatten()), a warning does
+/// not prevent a data flow from being instantiated. It instead points at a construct that is likely to be a mistake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintWarning {
+    /// A var is declared but is never used in the descriptor.
+    UnusedVar(Rc<str>),
+    /// An Operator does not declare any output: the result of its computations cannot reach a Sink.
+    NoEffect(NodeId),
+    /// The links form a cycle, which was explicitly allowed. The first node is repeated at the end.
+    Cycle(Vec<NodeId>),
+}
+
+impl Display for LintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintWarning::UnusedVar(var) => write!(
+                f,
+                "[unused-var] The var < {} > is declared but never used",
+                var
+            ),
+            LintWarning::NoEffect(node) => write!(
+                f,
+                "[no-effect] The Operator < {} > does not declare any output",
+                node
+            ),
+            LintWarning::Cycle(cycle) => write!(
+                f,
+                "[cycle] The links form a cycle: {}. Make sure that at least one node of the cycle does not wait for \
+                 data from its upstream node before producing, or the nodes will deadlock",
+                display_cycle(cycle)
+            ),
+        }
+    }
+}
+
+/// Returns a [LintWarning] for each of the [Vars] that is not used in the provided `descriptor`.
+///
+/// The `descriptor` is expected to be the textual representation of a descriptor, *before* its vars are expanded. See
+/// [Vars::unused_in] for the limitations of this check.
+pub(crate) fn lint_vars(descriptor: &str, vars: &Vars) -> Vec<LintWarning> {
+    vars.unused_in(descriptor)
+        .into_iter()
+        .map(LintWarning::UnusedVar)
+        .collect()
+}
+
+impl FlattenedDataFlowDescriptor {
+    /// Attempts to parse and flatten the data flow descriptor from the provided `content`, written in the provided
+    /// `format`, and returns the list of [LintWarning] detected in it.
+    ///
+    /// The warnings about unused vars come first, sorted alphabetically. They are followed by the warnings about the
+    /// Operators, in their order of declaration. A warning about a cycle, if any, comes last.
+    ///
+    /// The provided [Vars] overwrite the `vars` section of the descriptor, as in
+    /// [try_parse_from_str](FlattenedDataFlowDescriptor::try_parse_from_str()).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the descriptor could not be parsed or if its flattening failed: an invalid
+    /// data flow (e.g. an output that is not connected) is reported as such and not as a warning.
+    pub fn try_lint_from_str(
+        content: &str,
+        format: DescriptorFormat,
+        vars: Vars,
+    ) -> Result<Vec<LintWarning>> {
+        let declared_vars = match format {
+            DescriptorFormat::Json => {
+                serde_json::from_str::<Vars>(content).context("Failed to deserialize Vars")?
+            }
+            DescriptorFormat::Yaml => {
+                serde_yaml::from_str::<Vars>(content).context("Failed to deserialize Vars")?
+            }
+        };
+        // NOTE: The vars are checked before they are resolved, otherwise a var only used by another var would be
+        // reported as unused.
+        let mut warnings = lint_vars(content, &vars.clone().merge_overwrite(declared_vars));
+
+        let data_flow = Self::try_parse_from_str(content, format, vars)?;
+        warnings.append(&mut data_flow.lint());
+
+        Ok(warnings)
+    }
+
+    /// Returns the list of [LintWarning] detected in the nodes and links of this data flow.
+    pub(crate) fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = self
+            .operators
+            .iter()
+            .filter(|operator| operator.outputs.is_empty())
+            .map(|operator| LintWarning::NoEffect(operator.id.clone()))
+            .collect::<Vec<_>>();
+
+        if self.allow_cycles {
+            if let Some(cycle) = find_cycle(self) {
+                warnings.push(LintWarning::Cycle(cycle));
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+#[path = "./tests.rs"]
+mod tests;
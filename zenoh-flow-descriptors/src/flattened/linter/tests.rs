@@ -0,0 +1,102 @@
+//
+// Copyright (c) 2021 - 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use zenoh_flow_commons::{DescriptorFormat, Vars};
+
+use super::{lint_vars, LintWarning};
+use crate::FlattenedDataFlowDescriptor;
+
+#[test]
+fn test_lint() {
+    let descriptor = r#"
+name: data flow
+
+vars:
+  BUILD: debug
+  LIB_DIR: "file:///home/zenoh-flow/target/{{ BUILD }}"
+  DLL_EXT: so
+  UNUSED: unused
+
+sources:
+  - id: source-0
+    description: my source
+    library: "{{ LIB_DIR }}/libsource.{{ DLL_EXT }}"
+    outputs:
+      - out-0
+      - out-1
+
+operators:
+  - id: operator-0
+    description: my operator
+    library: "{{ LIB_DIR }}/liboperator.{{ DLL_EXT }}"
+    inputs:
+      - in-0
+    outputs: []
+
+sinks:
+  - id: sink-0
+    description: my sink
+    library: "{{ LIB_DIR }}/libsink.{{DLL_EXT}}"
+    inputs:
+      - in-0
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-0
+      input: in-0
+  - from:
+      node: source-0
+      output: out-1
+    to:
+      node: sink-0
+      input: in-0
+"#;
+
+    let warnings = FlattenedDataFlowDescriptor::try_lint_from_str(
+        descriptor,
+        DescriptorFormat::Yaml,
+        Vars::from([("OVERWRITTEN_UNUSED", "unused")]),
+    )
+    .expect("Failed to lint data flow");
+    assert_eq!(
+        vec![
+            LintWarning::UnusedVar("OVERWRITTEN_UNUSED".into()),
+            LintWarning::UnusedVar("UNUSED".into()),
+            LintWarning::NoEffect("operator-0".into()),
+        ],
+        warnings
+    );
+    assert_eq!(
+        "[unused-var] The var < UNUSED > is declared but never used",
+        warnings[1].to_string()
+    );
+
+    // An output that is not connected makes the data flow invalid: it is an error, not a warning.
+    assert!(FlattenedDataFlowDescriptor::try_lint_from_str(
+        &descriptor.replace("      - out-1\n", "      - out-1\n      - out-unconsumed\n"),
+        DescriptorFormat::Yaml,
+        Vars::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_lint_vars_invalid_template() {
+    // A template that cannot be rendered does not produce any warning: the error will be reported when parsing.
+    let vars = Vars::from([("UNUSED", "unused")]);
+    assert!(lint_vars("library: {{ MISSING }}", &vars).is_empty());
+}
@@ -20,6 +20,7 @@ pub use nodes::source::FlattenedSourceDescriptor;
 pub(crate) mod dataflow;
 pub use dataflow::FlattenedDataFlowDescriptor;
 
+pub(crate) mod linter;
 pub(crate) mod validator;
 
 use crate::nodes::operator::composite::{CompositeInputDescriptor, CompositeOutputDescriptor};
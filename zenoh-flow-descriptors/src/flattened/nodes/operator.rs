@@ -33,7 +33,12 @@ pub struct FlattenedOperatorDescriptor {
     pub id: NodeId,
     /// A human-readable description of the Operator.
     pub description: Option<Arc<str>>,
+    /// The group the Operator is part of, if any.
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     /// The path to the implementation of the Operator.
+    ///
+    /// A Url with the scheme `inline://<name>` references an Operator registered on the Runtime under that name.
     #[serde(alias = "Library")]
     pub library: Url,
     /// The identifiers of the inputs the Operator uses.
@@ -83,6 +88,7 @@ impl FlattenedOperatorDescriptor {
     /// - we cannot retrieve the remote descriptor,
     /// - we failed to parse the remote descriptor into either a regular Operator or a Composite,
     /// - we are expanding a Composite that we have already expanded before, effectively creating an infinite loop,
+    /// - an Operator within a Composite is disabled,
     /// - we failed to flatten an Operator within a Composite for any of the above reasons.
     pub(crate) fn try_flatten(
         operator_descriptor: OperatorDescriptor,
@@ -91,6 +97,15 @@ impl FlattenedOperatorDescriptor {
         overwritting_vars: Vars,
         ancestors: &mut HashSet<Url>,
     ) -> Result<(Vec<Self>, Vec<LinkDescriptor>, Patch)> {
+        // NOTE: The disabled Operators declared at the data flow level are bypassed before being flattened. Hence, if
+        // we reach this point with a disabled Operator, it is declared within a Composite.
+        if !operator_descriptor.enabled {
+            bail!(
+                "The Operator < {} > is disabled: only Operators declared at the data flow level can be disabled",
+                operator_descriptor.id
+            );
+        }
+
         let descriptor = match operator_descriptor.variant {
             OperatorVariants::Remote(remote_desc) => {
                 if !ancestors.insert(remote_desc.descriptor.clone()) {
@@ -133,6 +148,7 @@ Possible infinite recursion detected, the following descriptor appears to includ
                 vec![Self {
                     id: operator_descriptor.id,
                     description: custom_desc.description,
+                    group: operator_descriptor.group,
                     library: custom_desc.library,
                     inputs: custom_desc.inputs,
                     outputs: custom_desc.outputs,
@@ -168,10 +184,14 @@ Possible infinite recursion detected, the following descriptor appears to includ
                 }
 
                 // We have processed all operators. Time to patch.
-                // 1. Prepend each operator id with the id of the composite.
+                // 1. Prepend each operator id with the id of the composite and apply its group, if any.
                 let subs_nodes: Substitutions<NodeId> = flattened_operators
                     .iter_mut()
                     .map(|flat_op| {
+                        if operator_descriptor.group.is_some() {
+                            flat_op.group = operator_descriptor.group.clone();
+                        }
+
                         let old_id = flat_op.id.clone();
                         let composite_id: NodeId =
                             format!("{}>{}", &operator_descriptor.id, &old_id).into();
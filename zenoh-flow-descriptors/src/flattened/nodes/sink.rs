@@ -31,6 +31,9 @@ pub struct FlattenedSinkDescriptor {
     pub id: NodeId,
     /// A human-readable description of the Sink.
     pub description: Option<Arc<str>>,
+    /// The group the Sink is part of, if any.
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     /// The type of implementation of the Sink, either built-in or a path to a Library.
     #[serde(flatten)]
     pub sink: SinkVariant,
@@ -118,6 +121,7 @@ impl FlattenedSinkDescriptor {
             LocalSinkVariants::Custom(custom_sink) => Ok(Self {
                 id: sink_desc.id,
                 description: custom_sink.description,
+                group: sink_desc.group,
                 sink: SinkVariant::Library(custom_sink.library),
                 inputs: custom_sink.inputs,
                 configuration: overwritting_configuration
@@ -126,6 +130,7 @@ impl FlattenedSinkDescriptor {
             LocalSinkVariants::Zenoh(zenoh_desc) => Ok(Self {
                 id: sink_desc.id,
                 description: zenoh_desc.description,
+                group: sink_desc.group,
                 inputs: zenoh_desc.publishers.keys().cloned().collect(),
                 sink: SinkVariant::Zenoh(zenoh_desc.publishers),
                 configuration: Configuration::default(),
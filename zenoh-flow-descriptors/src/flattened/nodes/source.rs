@@ -31,6 +31,9 @@ pub struct FlattenedSourceDescriptor {
     pub id: NodeId,
     /// A human-readable description of the Source.
     pub description: Option<Arc<str>>,
+    /// The group the Source is part of, if any.
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     /// The type of implementation of the Source, either built-in or a path to a Library.
     #[serde(flatten)]
     pub source: SourceVariant,
@@ -118,6 +121,7 @@ impl FlattenedSourceDescriptor {
             LocalSourceVariants::Custom(custom_source) => Ok(Self {
                 id: source_desc.id,
                 description: custom_source.description,
+                group: source_desc.group,
                 source: SourceVariant::Library(custom_source.library),
                 outputs: custom_source.outputs,
                 configuration: overwritting_configuration
@@ -126,6 +130,7 @@ impl FlattenedSourceDescriptor {
             LocalSourceVariants::Zenoh(zenoh_desc) => Ok(Self {
                 id: source_desc.id,
                 description: zenoh_desc.description,
+                group: source_desc.group,
                 outputs: zenoh_desc.subscribers.keys().cloned().collect(),
                 source: SourceVariant::Zenoh(zenoh_desc.subscribers),
                 configuration: Configuration::default(),
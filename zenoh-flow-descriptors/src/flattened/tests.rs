@@ -27,7 +27,7 @@ use crate::{
 use serde_json::json;
 use url::Url;
 use uuid::Uuid;
-use zenoh_flow_commons::{NodeId, RuntimeId, Vars};
+use zenoh_flow_commons::{node, port, DescriptorFormat, NodeId, RuntimeId, Vars};
 
 const BASE_DIR: &str = "./tests/descriptors";
 const SCHEME: &str = "file://";
@@ -68,6 +68,7 @@ fn test_flatten_descriptor() {
         FlattenedSourceDescriptor {
             id: "source-1".into(),
             description: Some("source".into()),
+            group: None,
             outputs: vec!["source-out".into()],
             source: SourceVariant::Library(Url::parse("file://source.so").unwrap()),
             configuration: json!({ "foo": "global-outer" }).into(),
@@ -75,6 +76,7 @@ fn test_flatten_descriptor() {
         FlattenedSourceDescriptor {
             id: "source-2".into(),
             description: Some("source".into()),
+            group: None,
             outputs: vec!["source-out".into()],
             source: SourceVariant::Library(Url::parse("file://source.so").unwrap()),
             configuration: json!({ "foo": "global-outer" }).into(),
@@ -82,6 +84,7 @@ fn test_flatten_descriptor() {
         FlattenedSourceDescriptor {
             id: "source-composite".into(),
             description: Some("composite-source".into()),
+            group: None,
             outputs: vec![
                 "source-composite-out-1".into(),
                 "source-composite-out-2".into(),
@@ -108,6 +111,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-1".into(),
             description: Some("Outer description".into()),
+            group: None,
             inputs: vec!["operator-in".into()],
             outputs: vec!["operator-out".into()],
             library: Url::parse("file://operator.so").unwrap(),
@@ -116,6 +120,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-2".into(),
             description: Some("operator".into()),
+            group: None,
             inputs: vec!["operator-in".into()],
             outputs: vec!["operator-out".into()],
             library: Url::parse("file://operator.so").unwrap(),
@@ -133,6 +138,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-composite>sub-operator-1".into(),
             description: Some("leaf-operator-1".into()),
+            group: None,
             inputs: vec!["sub-operator-1-in-1".into(), "sub-operator-1-in-2".into()],
             outputs: vec!["sub-operator-1-out".into()],
             library: Url::parse("file://sub-operator-1.so").unwrap(),
@@ -149,6 +155,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-composite>sub-operator-composite>sub-sub-operator-1".into(),
             description: Some("sub-leaf-operator-1".into()),
+            group: None,
             inputs: vec!["sub-sub-operator-1-in".into()],
             outputs: vec!["sub-sub-operator-1-out".into()],
             library: Url::parse("file://sub-sub-operator-1.so").unwrap(),
@@ -161,6 +168,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-composite>sub-operator-composite>sub-sub-operator-2".into(),
             description: Some("sub-leaf-operator-2".into()),
+            group: None,
             inputs: vec!["sub-sub-operator-2-in".into()],
             outputs: vec!["sub-sub-operator-2-out".into()],
             library: Url::parse("file://sub-sub-operator-2.so").unwrap(),
@@ -173,6 +181,7 @@ fn test_flatten_descriptor() {
         FlattenedOperatorDescriptor {
             id: "operator-composite>sub-operator-2".into(),
             description: Some("leaf-operator-2".into()),
+            group: None,
             inputs: vec!["sub-operator-2-in".into()],
             outputs: vec!["sub-operator-2-out-1".into(), "sub-operator-2-out-2".into()],
             library: Url::parse("file://sub-operator-2.so").unwrap(),
@@ -199,6 +208,7 @@ fn test_flatten_descriptor() {
         FlattenedSinkDescriptor {
             id: "sink-1".into(),
             description: Some("sink".into()),
+            group: None,
             inputs: vec!["sink-in".into()],
             sink: SinkVariant::Library(Url::parse("file://sink.so").unwrap()),
             configuration: json!({ "foo": "global-outer" }).into(),
@@ -206,6 +216,7 @@ fn test_flatten_descriptor() {
         FlattenedSinkDescriptor {
             id: "sink-2".into(),
             description: Some("sink".into()),
+            group: None,
             inputs: vec!["sink-in".into()],
             sink: SinkVariant::Library(Url::parse("file://sink.so").unwrap()),
             configuration: json!({ "foo": "global-outer" }).into(),
@@ -213,6 +224,7 @@ fn test_flatten_descriptor() {
         FlattenedSinkDescriptor {
             id: "sink-composite".into(),
             description: Some("composite-sink".into()),
+            group: None,
             inputs: vec!["sink-composite-in-1".into(), "sink-composite-in-2".into()],
             sink: SinkVariant::Library(Url::parse("file://sink-composite.so").unwrap()),
             configuration: json!({ "foo": "global-outer", "bar": "reverse" }).into(),
@@ -334,6 +346,54 @@ fn test_flatten_descriptor() {
     assert_eq!(expected_mapping, flatten.mapping);
 }
 
+#[test]
+fn test_flatten_group() {
+    let base_dir = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), BASE_DIR);
+
+    let (mut descriptor, vars) = try_load_descriptor::<DataFlowDescriptor>(
+        &Url::parse(&format!("file://{}/data-flow.yml", base_dir)).unwrap(),
+        Vars::from([
+            ("BASE_DIR", base_dir.as_str()),
+            ("RUNTIME_1", format!("{}", RuntimeId::rand()).as_str()),
+            ("RUNTIME_2", format!("{}", RuntimeId::rand()).as_str()),
+            (
+                "RUNTIME_COMPOSITE",
+                format!("{}", RuntimeId::rand()).as_str(),
+            ),
+        ]),
+    )
+    .expect("Failed to load DataFlowDescriptor");
+
+    descriptor
+        .sources
+        .iter_mut()
+        .filter(|source| source.id.as_ref() == "source-1")
+        .for_each(|source| source.group = Some("acquisition".into()));
+    descriptor
+        .operators
+        .iter_mut()
+        .filter(|operator| operator.id.as_ref() == "operator-composite")
+        .for_each(|operator| operator.group = Some("processing".into()));
+
+    let flatten = FlattenedDataFlowDescriptor::try_flatten(descriptor, vars).unwrap();
+
+    for source in &flatten.sources {
+        let expected = (source.id.as_ref() == "source-1").then(|| "acquisition".into());
+        assert_eq!(expected, source.group, "Source < {} >", source.id);
+    }
+
+    // The group of a composite Operator applies to all the Operators it is composed of.
+    for operator in &flatten.operators {
+        let expected = operator
+            .id
+            .starts_with("operator-composite>")
+            .then(|| "processing".into());
+        assert_eq!(expected, operator.group, "Operator < {} >", operator.id);
+    }
+
+    assert!(flatten.sinks.iter().all(|sink| sink.group.is_none()));
+}
+
 #[test]
 fn test_detect_recursion() {
     let base_dir = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), BASE_DIR);
@@ -485,3 +545,583 @@ links:
     );
     assert!(flat_flow_yaml.mapping.is_empty());
 }
+
+#[test]
+fn test_identifiers_macros() {
+    const SOURCE: &str = "source-0";
+    const SINK: &str = "sink-0";
+    const OUTPUT: &str = "out-0";
+    const INPUT: &str = "in-0";
+
+    let flow_yaml = format!(
+        r#"
+name: test-flow
+
+sources:
+  - id: {SOURCE}
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - {OUTPUT}
+
+sinks:
+  - id: {SINK}
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - {INPUT}
+
+links:
+  - from:
+      node: {SOURCE}
+      output: {OUTPUT}
+    to:
+      node: {SINK}
+      input: {INPUT}
+"#
+    );
+
+    let flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(&flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+
+    assert_eq!(node!(SOURCE), flatten.sources[0].id);
+    assert_eq!(vec![port!(OUTPUT)], flatten.sources[0].outputs);
+    assert_eq!(node!(SINK), flatten.sinks[0].id);
+    assert_eq!(vec![port!(INPUT)], flatten.sinks[0].inputs);
+    assert_eq!(
+        vec![LinkDescriptor::new(
+            OutputDescriptor::new(node!(SOURCE), port!(OUTPUT)),
+            InputDescriptor::new(node!(SINK), port!(INPUT)),
+        )],
+        flatten.links
+    );
+}
+
+#[test]
+fn test_disabled_operator_is_bypassed() {
+    let flow_yaml = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-1
+    library: "file:///home/zenoh-flow/liboperator.so"
+    enabled: false
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+  - id: operator-2
+    library: "file:///home/zenoh-flow/liboperator.so"
+    inputs:
+      - in-2
+    outputs:
+      - out-2
+
+sinks:
+  - id: sink-3
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-3
+
+  - id: sink-4
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-4
+
+mapping:
+  a100ae41d10b4ec58dccba4cfa30d732:
+    - operator-1
+  d8c50f6160154e409c77b61866c5cb47:
+    - operator-1
+    - sink-4
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-1
+      input: in-1
+
+  - from:
+      node: operator-1
+      output: out-1
+    to:
+      node: operator-2
+      input: in-2
+
+  - from:
+      node: operator-1
+      output: out-1
+    to:
+      node: sink-3
+      input: in-3
+
+  - from:
+      node: operator-2
+      output: out-2
+    to:
+      node: sink-4
+      input: in-4
+"#;
+
+    let flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+
+    assert_eq!(1, flatten.operators.len());
+    assert_eq!(NodeId::from("operator-2"), flatten.operators[0].id);
+
+    let expected_links = vec![
+        LinkDescriptor::new(
+            OutputDescriptor::new("source-0", "out-0"),
+            InputDescriptor::new("operator-2", "in-2"),
+        ),
+        LinkDescriptor::new(
+            OutputDescriptor::new("source-0", "out-0"),
+            InputDescriptor::new("sink-3", "in-3"),
+        ),
+        LinkDescriptor::new(
+            OutputDescriptor::new("operator-2", "out-2"),
+            InputDescriptor::new("sink-4", "in-4"),
+        ),
+    ];
+
+    expected_links.iter().for_each(|expected_link| {
+        assert!(
+            flatten.links.contains(expected_link),
+            "Link missing or incorrect: \n\n (expected) {:?} \n\n {:?}",
+            expected_link,
+            flatten.links
+        )
+    });
+    assert_eq!(expected_links.len(), flatten.links.len());
+
+    // Same as when removing a node: the runtimes with no node left are no longer in the mapping.
+    assert_eq!(
+        HashMap::from([(
+            RuntimeId::from_str("d8c50f6160154e409c77b61866c5cb47").unwrap(),
+            HashSet::from([NodeId::from("sink-4")]),
+        )]),
+        flatten.mapping
+    );
+
+    // An Operator with several incoming links cannot be bypassed.
+    let flow_yaml = flow_yaml
+        .replace("    enabled: false\n", "")
+        .replace(
+            "  - id: operator-2\n",
+            "  - id: operator-2\n    enabled: false\n",
+        )
+        .replace("      - in-2\n", "      - in-2\n      - in-2-bis\n")
+        + r#"
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-2
+      input: in-2-bis
+"#;
+
+    let res = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(&flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    );
+    assert!(format!("{:?}", res).contains("it must have exactly one incoming link, found 2"));
+}
+
+#[test]
+fn test_source_with_multiple_outputs() {
+    let flow_yaml = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+      - out-1
+
+sinks:
+  - id: sink-0
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-0
+
+  - id: sink-1
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-1
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: sink-0
+      input: in-0
+
+  - from:
+      node: source-0
+      output: out-1
+    to:
+      node: sink-1
+      input: in-1
+"#;
+
+    let flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+
+    assert_eq!(
+        vec![port!("out-0"), port!("out-1")],
+        flatten.sources[0].outputs
+    );
+
+    let expected_links = vec![
+        LinkDescriptor::new(
+            OutputDescriptor::new("source-0", "out-0"),
+            InputDescriptor::new("sink-0", "in-0"),
+        ),
+        LinkDescriptor::new(
+            OutputDescriptor::new("source-0", "out-1"),
+            InputDescriptor::new("sink-1", "in-1"),
+        ),
+    ];
+    assert_eq!(expected_links, flatten.links);
+
+    // A link cannot start from an output the Source does not declare.
+    let flow_yaml = flow_yaml.replace("      output: out-1\n", "      output: out-2\n");
+    assert!(FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(&flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_parse_from_str() {
+    let flow_yaml = r#"
+name: test-flow
+
+vars:
+  SINK_INPUT: in-0
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-0
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - "{{ SINK_INPUT }}"
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: sink-0
+      input: "{{ SINK_INPUT }}"
+"#;
+
+    let flatten = FlattenedDataFlowDescriptor::try_parse_from_str(
+        flow_yaml,
+        DescriptorFormat::Yaml,
+        Vars::from([("SINK_INPUT", "in-1")]),
+    )
+    .expect("Failed to parse flow");
+    assert_eq!(vec![port!("in-1")], flatten.sinks[0].inputs);
+
+    let flow_json = serde_json::to_string(&flatten).expect("Failed to serialize flow as JSON");
+    assert_eq!(
+        flatten,
+        FlattenedDataFlowDescriptor::try_parse_from_str(
+            &flow_json,
+            DescriptorFormat::Json,
+            Vars::default()
+        )
+        .expect("Failed to parse flow from JSON")
+    );
+
+    assert!(FlattenedDataFlowDescriptor::try_parse_from_str(
+        flow_yaml,
+        DescriptorFormat::Json,
+        Vars::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_remove_node_and_link() {
+    let flow_yaml = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-1
+    library: "file:///home/zenoh-flow/liboperator.so"
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-2
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-2
+
+  - id: sink-3
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-3
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-1
+      input: in-1
+
+  - from:
+      node: operator-1
+      output: out-1
+    to:
+      node: sink-2
+      input: in-2
+
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: sink-3
+      input: in-3
+"#;
+
+    let mut flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+    let original = flatten.clone();
+
+    // Removing the Operator leaves the input of `sink-2` unconnected.
+    assert!(flatten
+        .try_remove_node(&node!("operator-1"), false)
+        .is_err());
+    assert_eq!(original, flatten);
+
+    assert!(flatten.try_remove_node(&node!("unknown"), true).is_err());
+
+    flatten
+        .try_remove_node(&node!("sink-3"), false)
+        .expect("Failed to remove sink-3");
+    assert!(flatten.sinks.iter().all(|sink| sink.id != node!("sink-3")));
+    assert_eq!(2, flatten.links.len());
+
+    let link = LinkDescriptor::new(
+        OutputDescriptor::new("operator-1", "out-1"),
+        InputDescriptor::new("sink-2", "in-2"),
+    );
+    assert!(flatten.try_remove_link(&link, false).is_err());
+    flatten
+        .try_remove_link(&link, true)
+        .expect("Failed to force the removal of the link");
+    assert!(!flatten.links.contains(&link));
+    assert!(flatten.try_remove_link(&link, true).is_err());
+}
+
+#[test]
+fn test_parse_from_bytes_malformed() {
+    let deeply_nested = format!("{}{}", "[".repeat(10_000), "]".repeat(10_000));
+    let malformed: Vec<&[u8]> = vec![
+        b"",
+        b"\xff\xfe\xfd",
+        b"{{",
+        b"{{ UNKNOWN }}",
+        b"name: [unbalanced",
+        b"vars: 42\nname: flow",
+        b"vars:\n  A: {{ A }}\nname: {{ A }}",
+        b"name: flow\nsources: 12\nsinks: []\nlinks: []",
+        b"name: flow\nsources: []\nsinks: []\nlinks: []",
+        b"id: not-a-uuid\nname: flow\nsources: []\nsinks: []\nlinks: []",
+        b"name: flow\nsources:\n  - id: '*'\n    library: file:///lib.so\n    outputs: []\nsinks: []\nlinks: []",
+        b"\0\0\0\0",
+        deeply_nested.as_bytes(),
+    ];
+
+    for input in malformed {
+        for format in [DescriptorFormat::Yaml, DescriptorFormat::Json] {
+            assert!(
+                FlattenedDataFlowDescriptor::try_parse_from_bytes(input, format, Vars::default())
+                    .is_err(),
+                "Expected an error for input: {:?}",
+                String::from_utf8_lossy(input)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_topological_sort() {
+    let flow_yaml = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-2
+    library: "file:///home/zenoh-flow/liboperator.so"
+    inputs:
+      - in-2
+    outputs:
+      - out-2
+
+  - id: operator-1
+    library: "file:///home/zenoh-flow/liboperator.so"
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-3
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-3
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-1
+      input: in-1
+
+  - from:
+      node: operator-1
+      output: out-1
+    to:
+      node: operator-2
+      input: in-2
+
+  - from:
+      node: operator-2
+      output: out-2
+    to:
+      node: sink-3
+      input: in-3
+"#;
+
+    let mut flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+
+    assert_eq!(
+        vec![
+            node!("source-0"),
+            node!("operator-1"),
+            node!("operator-2"),
+            node!("sink-3")
+        ],
+        flatten.topological_sort().expect("The data flow is a DAG")
+    );
+
+    // Feed the output of `operator-2` back to `operator-1`.
+    flatten.links[0].from = OutputDescriptor::new("operator-2", "out-2");
+    let error = flatten
+        .topological_sort()
+        .expect_err("The data flow contains a cycle");
+    assert!(error
+        .to_string()
+        .contains("operator-2 -> operator-1 -> operator-2"));
+}
+
+#[test]
+fn test_is_bipartite_between() {
+    let flow_yaml = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: "file:///home/zenoh-flow/libsource.so"
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-1
+    library: "file:///home/zenoh-flow/liboperator.so"
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-2
+    library: "file:///home/zenoh-flow/libsink.so"
+    inputs:
+      - in-2
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: operator-1
+      input: in-1
+
+  - from:
+      node: operator-1
+      output: out-1
+    to:
+      node: sink-2
+      input: in-2
+"#;
+
+    let flatten = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(flow_yaml).expect("Failed to deserialize flow from YAML"),
+        Vars::default(),
+    )
+    .expect("Failed to flatten flow");
+
+    assert!(flatten.is_bipartite_between(
+        &["source-0".into(), "sink-2".into()],
+        &["operator-1".into()]
+    ));
+    assert!(!flatten.is_bipartite_between(
+        &["source-0".into(), "operator-1".into()],
+        &["sink-2".into()]
+    ));
+    assert!(!flatten.is_bipartite_between(
+        &["source-0".into()],
+        &["operator-1".into(), "sink-2".into()]
+    ));
+}
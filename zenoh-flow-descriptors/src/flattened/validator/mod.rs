@@ -14,7 +14,7 @@
 
 use crate::FlattenedDataFlowDescriptor;
 use anyhow::bail;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use zenoh_flow_commons::{NodeId, PortId, Result};
 
 #[derive(Default)]
@@ -177,10 +177,88 @@ We have detected several links that point the same Input < {} >:
             bail!(error_message);
         }
 
+        if !data_flow.allow_cycles {
+            if let Some(cycle) = find_cycle(data_flow) {
+                bail!(
+                    r#"
+The links of the data flow form the following cycle:
+{}
+
+If this cycle is intended, set `allow-cycles: true` in the data flow descriptor.
+"#,
+                    display_cycle(&cycle)
+                );
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Returns the nodes forming a cycle in the provided data flow, if there is (at least) one.
+///
+/// The first node of the returned list is repeated at its end.
+pub(crate) fn find_cycle(data_flow: &FlattenedDataFlowDescriptor) -> Option<Vec<NodeId>> {
+    let mut successors: HashMap<&NodeId, Vec<&NodeId>> = HashMap::default();
+    for link in data_flow.links.iter() {
+        successors
+            .entry(&link.from.node)
+            .or_default()
+            .push(&link.to.node);
+    }
+
+    // NOTE: Only the Operators can be part of a cycle: Sources have no input and Sinks have no output.
+    let mut visited = HashSet::new();
+    for operator in data_flow.operators.iter() {
+        let mut path = Vec::new();
+        if let Some(cycle) = visit(&operator.id, &successors, &mut visited, &mut path) {
+            return Some(cycle);
+        }
+    }
+
+    None
+}
+
+// Depth-first search: `path` holds the nodes currently being explored, `visited` the nodes fully explored.
+fn visit<'a>(
+    node: &'a NodeId,
+    successors: &HashMap<&'a NodeId, Vec<&'a NodeId>>,
+    visited: &mut HashSet<&'a NodeId>,
+    path: &mut Vec<&'a NodeId>,
+) -> Option<Vec<NodeId>> {
+    if let Some(position) = path.iter().position(|&n| n == node) {
+        let mut cycle = path[position..]
+            .iter()
+            .map(|&n| n.clone())
+            .collect::<Vec<_>>();
+        cycle.push(node.clone());
+        return Some(cycle);
+    }
+
+    if visited.contains(node) {
+        return None;
+    }
+
+    path.push(node);
+    for &successor in successors.get(node).into_iter().flatten() {
+        if let Some(cycle) = visit(successor, successors, visited, path) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    visited.insert(node);
+
+    None
+}
+
+pub(crate) fn display_cycle(cycle: &[NodeId]) -> String {
+    cycle
+        .iter()
+        .map(|node| node.to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 #[cfg(test)]
 #[path = "./tests.rs"]
 mod tests;
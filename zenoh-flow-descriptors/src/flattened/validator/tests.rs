@@ -14,7 +14,7 @@
 
 use zenoh_flow_commons::Vars;
 
-use crate::FlattenedDataFlowDescriptor;
+use crate::{FlattenedDataFlowDescriptor, LintWarning};
 
 #[test]
 fn test_valid_data_flow() {
@@ -930,3 +930,86 @@ links:
     assert!(format!("{:?}", res)
         .contains("We have detected several links that point the same Input < sink-0.in >:"));
 }
+
+#[test]
+fn test_cycle() {
+    let yaml_cycle = r#"
+name: data flow with a feedback loop
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+operators:
+  - id: controller
+    library: file:///home/zenoh-flow/liboperator.so
+    inputs:
+      - in-setpoint
+      - in-feedback
+    outputs:
+      - out-command
+
+  - id: plant
+    library: file:///home/zenoh-flow/liboperator.so
+    inputs:
+      - in-command
+    outputs:
+      - out-state
+
+sinks:
+  - id: sink-0
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-0
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: controller
+      input: in-setpoint
+  - from:
+      node: controller
+      output: out-command
+    to:
+      node: plant
+      input: in-command
+  - from:
+      node: plant
+      output: out-state
+    to:
+      node: controller
+      input: in-feedback
+  - from:
+      node: plant
+      output: out-state
+    to:
+      node: sink-0
+      input: in-0
+"#;
+
+    let error = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(yaml_cycle).unwrap(),
+        Vars::default(),
+    )
+    .expect_err("A cycle should be rejected by default");
+    assert!(format!("{:?}", error).contains("controller -> plant -> controller"));
+
+    let flattened = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str(&format!("allow-cycles: true\n{}", yaml_cycle)).unwrap(),
+        Vars::default(),
+    )
+    .expect("An allowed cycle should not be rejected");
+    assert!(flattened.allow_cycles);
+    assert_eq!(
+        vec![LintWarning::Cycle(vec![
+            "controller".into(),
+            "plant".into(),
+            "controller".into()
+        ])],
+        flattened.lint()
+    );
+}
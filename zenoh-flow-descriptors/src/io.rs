@@ -84,11 +84,36 @@ impl OutputDescriptor {
     }
 }
 
+/// The reliability of a link that crosses Zenoh-Flow runtimes, applied to the Zenoh subscriber receiving the data.
+///
+/// It defaults to Zenoh's default: `best_effort`.
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Reliability {
+    #[default]
+    BestEffort,
+    Reliable,
+}
+
+/// The congestion control of a link that crosses Zenoh-Flow runtimes, applied to the Zenoh publication sending the
+/// data.
+///
+/// It defaults to Zenoh's default: `drop`.
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CongestionControl {
+    #[default]
+    Drop,
+    Block,
+}
+
 /// A `LinkDescriptor` describes a link in Zenoh-Flow: a connection from an Output to an Input.
 ///
 /// A link is composed of:
 /// - an [OutputDescriptor],
 /// - an [InputDescriptor],
+/// - *(optional, Zenoh's defaults)* the [Reliability] and [CongestionControl] to use if the link crosses Zenoh-Flow
+///   runtimes --- they are ignored otherwise,
 /// - *(optional, disabled by default)* Zenoh shared-memory parameters.
 ///
 /// # Example
@@ -103,6 +128,8 @@ impl OutputDescriptor {
 /// to:
 ///   node : Sink
 ///   input : i-sink
+/// reliability: reliable     # optional, `best_effort` by default
+/// congestion_control: block # optional, `drop` by default
 /// # "#;
 /// # serde_yaml::from_str::<LinkDescriptor>(link_desc).unwrap();
 /// ```
@@ -110,6 +137,10 @@ impl OutputDescriptor {
 pub struct LinkDescriptor {
     pub from: OutputDescriptor,
     pub to: InputDescriptor,
+    #[serde(default)]
+    pub reliability: Reliability,
+    #[serde(default, alias = "congestion-control")]
+    pub congestion_control: CongestionControl,
     #[cfg(feature = "shared-memory")]
     #[serde(default, alias = "shm", alias = "shared-memory")]
     pub shared_memory: Option<SharedMemoryConfiguration>,
@@ -126,11 +157,23 @@ impl LinkDescriptor {
         Self {
             from,
             to,
+            reliability: Reliability::default(),
+            congestion_control: CongestionControl::default(),
             #[cfg(feature = "shared-memory")]
             shared_memory: None,
         }
     }
 
+    pub fn set_reliability(mut self, reliability: Reliability) -> Self {
+        self.reliability = reliability;
+        self
+    }
+
+    pub fn set_congestion_control(mut self, congestion_control: CongestionControl) -> Self {
+        self.congestion_control = congestion_control;
+        self
+    }
+
     #[cfg(feature = "shared-memory")]
     pub fn set_shared_memory(mut self, shm: SharedMemoryConfiguration) -> Self {
         self.shared_memory = Some(shm);
@@ -42,8 +42,9 @@ pub(crate) mod uri;
 
 pub use dataflow::DataFlowDescriptor;
 pub use flattened::dataflow::FlattenedDataFlowDescriptor;
+pub use flattened::linter::LintWarning;
 pub use flattened::nodes::operator::FlattenedOperatorDescriptor;
 pub use flattened::nodes::sink::{FlattenedSinkDescriptor, SinkVariant};
 pub use flattened::nodes::source::{FlattenedSourceDescriptor, SourceVariant};
 
-pub use io::{InputDescriptor, LinkDescriptor, OutputDescriptor};
+pub use io::{CongestionControl, InputDescriptor, LinkDescriptor, OutputDescriptor, Reliability};
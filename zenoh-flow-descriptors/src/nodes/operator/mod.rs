@@ -80,13 +80,44 @@ use zenoh_flow_commons::{Configuration, NodeId, PortId};
 /// configuration:
 ///   answer: 1
 /// ```
+///
+/// ## Disabled operator
+///
+/// An Operator, declared at the data flow level, can be disabled by setting `enabled` to `false`. A disabled Operator
+/// is bypassed: the data it receives is forwarded, unchanged, to the node(s) it is connected to. This requires the
+/// Operator to have a single incoming link and all its outgoing links to originate from the same output.
+///
+/// ```yaml
+/// id: my-operator-1
+/// descriptor: file:///home/zenoh-flow/my-operator.yaml
+/// enabled: false
+/// ```
+///
+/// ## Group
+///
+/// An Operator can be part of a named group, for instance to start or abort all the nodes of a group together. The
+/// group of a composite Operator applies to all the Operators it is composed of.
+///
+/// ```yaml
+/// id: my-operator-1
+/// descriptor: file:///home/zenoh-flow/my-operator.yaml
+/// group: processing
+/// ```
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct OperatorDescriptor {
     pub id: NodeId,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     #[serde(flatten)]
     pub variant: OperatorVariants,
 }
 
+fn enabled_by_default() -> bool {
+    true
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(untagged)]
 pub(crate) enum OperatorVariants {
@@ -65,9 +65,20 @@ use zenoh_flow_commons::{Configuration, NodeId, PortId};
 ///   key_1: key/expr/1
 /// ```
 ///
+/// ## Group
+///
+/// A Sink can be part of a named group, for instance to start or abort all the nodes of a group together.
+///
+/// ```yaml
+/// id: my-sink-0
+/// descriptor: file:///home/zenoh-flow/my-sink.yaml
+/// group: actuation
+/// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SinkDescriptor {
     pub id: NodeId,
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     #[serde(flatten)]
     pub variant: SinkVariants,
 }
@@ -64,9 +64,21 @@ use zenoh_flow_commons::{Configuration, NodeId, PortId};
 ///   ke-0: key/expr/0
 ///   ke-1: key/expr/1
 /// ```
+///
+/// ## Group
+///
+/// A Source can be part of a named group, for instance to start or abort all the nodes of a group together.
+///
+/// ```yaml
+/// id: my-source-0
+/// descriptor: file:///home/zenoh-flow/my-source.yaml
+/// group: acquisition
+/// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SourceDescriptor {
     pub id: NodeId,
+    #[serde(default)]
+    pub group: Option<Arc<str>>,
     #[serde(flatten)]
     pub variant: SourceVariants,
 }
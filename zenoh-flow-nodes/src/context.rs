@@ -14,19 +14,21 @@
 
 use std::sync::Arc;
 
-use zenoh_flow_commons::{InstanceId, RuntimeId};
+use zenoh_flow_commons::{InstanceId, NodeId, RuntimeId};
 
 /// The `Context` structure provides information about the data flow and the Zenoh-Flow runtime.
 ///
 /// In particular, it allows accessing:
 /// - the [name](Context::name()) of the data flow,
 /// - the [instance id](Context::instance_id()) of this instance of the data flow,
-/// - the [runtime id](Context::runtime_id()) of the Zenoh-Flow runtime managing the **node**.
+/// - the [runtime id](Context::runtime_id()) of the Zenoh-Flow runtime managing the **node**,
+/// - the [node id](Context::node_id()) of the node.
 #[derive(Clone, Debug)]
 pub struct Context {
     pub(crate) flow_name: Arc<str>,
     pub(crate) instance_id: InstanceId,
     pub(crate) runtime_id: RuntimeId,
+    pub(crate) node_id: Option<NodeId>,
 }
 
 impl Context {
@@ -36,9 +38,16 @@ impl Context {
             flow_name,
             instance_id,
             runtime_id,
+            node_id: None,
         }
     }
 
+    /// Sets the identifier of the node this `Context` is given to.
+    pub fn with_node_id(mut self, node_id: NodeId) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
     /// Returns the name of the data flow.
     ///
     /// Note all instances of the same data flow will share the same `name`.
@@ -58,4 +67,28 @@ impl Context {
     pub fn runtime_id(&self) -> &RuntimeId {
         &self.runtime_id
     }
+
+    /// Returns the unique identifier of the node, if it was set by the Zenoh-Flow runtime.
+    pub fn node_id(&self) -> Option<&NodeId> {
+        self.node_id.as_ref()
+    }
+
+    /// Logs the provided `message` at the provided `level`, with the target `zenoh_flow::<instance id>::<node id>`.
+    ///
+    /// This target allows filtering the messages of a node through the `RUST_LOG` environment variable, e.g.
+    /// `RUST_LOG=zenoh_flow::<instance id>::<node id>=debug`, or the messages of all the nodes of an instance with
+    /// `RUST_LOG=zenoh_flow::<instance id>=debug`. If the node id was not set, `?` replaces it.
+    ///
+    /// The message is logged through the [log] crate: the target of a `tracing` event has to be known at compile time.
+    /// The Zenoh-Flow binaries forward these messages to their `tracing` subscriber.
+    pub fn log(&self, level: log::Level, message: &str) {
+        let node = self.node_id.as_ref().map(|id| id.as_ref()).unwrap_or("?");
+        let target = format!("zenoh_flow::{}::{}", self.instance_id, node);
+
+        log::log!(target: &target, level, "{}", message);
+    }
 }
+
+#[cfg(test)]
+#[path = "./tests/context-tests.rs"]
+mod tests;
@@ -159,6 +159,7 @@ impl InputBuilder {
         InputRaw {
             port_id: self.port_id,
             receiver: self.receiver,
+            coalescing: false,
         }
     }
 
@@ -191,6 +192,7 @@ impl InputBuilder {
         Input {
             input_raw: self.raw(),
             deserializer: Arc::new(deserializer),
+            default: None,
         }
     }
 }
@@ -205,6 +207,7 @@ impl InputBuilder {
 pub struct InputRaw {
     pub(crate) port_id: PortId,
     pub(crate) receiver: flume::Receiver<LinkMessage>,
+    pub(crate) coalescing: bool,
 }
 
 impl InputRaw {
@@ -212,6 +215,28 @@ impl InputRaw {
         &self.port_id
     }
 
+    /// Sets this Input in *coalescing* mode: when receiving, only the most recent queued [LinkMessage] is returned and
+    /// all the older ones are discarded.
+    ///
+    /// This mode is useful when a node is slower than its upstream node(s) and only the freshest data matters (e.g. the
+    /// last known position of a robot): instead of processing a backlog of outdated messages, the node always processes
+    /// the latest one.
+    pub fn coalescing(mut self) -> Self {
+        self.coalescing = true;
+        self
+    }
+
+    /// If this Input is in coalescing mode, discards all the queued messages but the most recent one.
+    fn coalesce(&self, mut message: LinkMessage) -> LinkMessage {
+        if self.coalescing {
+            while let Ok(newer_message) = self.receiver.try_recv() {
+                message = newer_message;
+            }
+        }
+
+        message
+    }
+
     /// Returns the number of channels associated with this Input.
     pub fn channels_count(&self) -> usize {
         self.receiver.len()
@@ -219,6 +244,9 @@ impl InputRaw {
 
     /// Returns the first queued [LinkMessage] or [None] if there is no queued message.
     ///
+    /// If this Input is in [coalescing](InputRaw::coalescing()) mode, the most recent queued [LinkMessage] is returned
+    /// instead and the older ones are discarded.
+    ///
     /// # Asynchronous alternative: `recv`
     ///
     /// This method is a synchronous fail-fast alternative to it's asynchronous counterpart: `recv`.  Although
@@ -229,7 +257,7 @@ impl InputRaw {
     /// An error is returned if the associated channel is disconnected.
     pub fn try_recv(&self) -> Result<Option<LinkMessage>> {
         match self.receiver.try_recv() {
-            Ok(message) => Ok(Some(message)),
+            Ok(message) => Ok(Some(self.coalesce(message))),
             Err(e) => match e {
                 TryRecvError::Empty => Ok(None),
                 TryRecvError::Disconnected => {
@@ -245,14 +273,21 @@ impl InputRaw {
     ///
     /// If several [LinkMessage] are received at the same time, one is *randomly* selected.
     ///
+    /// If this Input is in [coalescing](InputRaw::coalescing()) mode, the most recent queued [LinkMessage] is returned
+    /// instead and the older ones are discarded.
+    ///
     /// # Errors
     ///
     /// An error is returned if a channel was disconnected.
     pub async fn recv(&self) -> Result<LinkMessage> {
-        self.receiver.recv_async().await.map_err(|_| {
-            tracing::error!("Link disconnected: {}", self.port_id);
-            anyhow!("Disconnected")
-        })
+        self.receiver
+            .recv_async()
+            .await
+            .map(|message| self.coalesce(message))
+            .map_err(|_| {
+                tracing::error!("Link disconnected: {}", self.port_id);
+                anyhow!("Disconnected")
+            })
     }
 }
 
@@ -270,8 +305,11 @@ impl InputRaw {
 pub struct Input<T> {
     pub(crate) input_raw: InputRaw,
     pub(crate) deserializer: Arc<DeserializerFn<T>>,
+    pub(crate) default: Option<Arc<DefaultFn<T>>>,
 }
 
+type DefaultFn<T> = dyn Fn() -> T + Send + Sync;
+
 // Dereferencing to the [InputRaw] allows to directly call methods on it with a typed [Input].
 impl<T: Send + Sync + 'static> Deref for Input<T> {
     type Target = InputRaw;
@@ -282,6 +320,38 @@ impl<T: Send + Sync + 'static> Deref for Input<T> {
 }
 
 impl<T: Send + Sync + 'static> Input<T> {
+    /// Sets this Input in *coalescing* mode, see [InputRaw::coalescing].
+    pub fn coalescing(mut self) -> Self {
+        self.input_raw.coalescing = true;
+        self
+    }
+
+    /// Sets the `default` value this [`Input<T>`](Input) will produce, through `try_recv_or_default`, when none of its
+    /// channels hold a message.
+    ///
+    /// This allows an [Operator](crate::prelude::Operator) to treat a missing input as present with a default value
+    /// instead of waiting for it to be produced.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use zenoh_flow_nodes::prelude::*;
+    /// # let mut inputs = Inputs::default();
+    /// let input: Input<u64> = inputs.take("test typed")
+    ///     .expect("No input name 'test typed' found")
+    ///     .typed(
+    ///         |bytes| serde_json::from_slice(bytes).map_err(|e| anyhow!(e))
+    ///     )
+    ///     .with_default(0);
+    /// ```
+    pub fn with_default(mut self, default: T) -> Self
+    where
+        T: Clone,
+    {
+        self.default = Some(Arc::new(move || default.clone()));
+        self
+    }
+
     /// Returns the first [`Data<T>`](Data) that was received, *asynchronously*, on any of the channels
     /// associated with this Input.
     ///
@@ -343,6 +413,30 @@ impl<T: Send + Sync + 'static> Input<T> {
 
         Ok(None)
     }
+
+    /// Returns the first [`Data<T>`](Data) that was received on any of the channels associated with this Input or, if
+    /// all the channels are empty, the default value set with `with_default`.
+    ///
+    /// As the default value was not received, it is not associated with a [Timestamp]: in that case the second element
+    /// of the tuple is [None].
+    ///
+    /// [None] is returned if all the channels are empty and no default value was set.
+    ///
+    /// # Errors
+    ///
+    /// Several errors can occur:
+    /// - a channel was disconnected,
+    /// - Zenoh-Flow failed at interpreting the received data as an instance of `T`.
+    pub fn try_recv_or_default(&self) -> Result<Option<(Data<T>, Option<Timestamp>)>> {
+        if let Some((data, timestamp)) = self.try_recv()? {
+            return Ok(Some((data, Some(timestamp))));
+        }
+
+        Ok(self
+            .default
+            .as_ref()
+            .map(|default| (Data::from(default()), None)))
+    }
 }
 
 #[cfg(test)]
@@ -16,7 +16,7 @@ use prost::Message as pMessage;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use super::{Input, InputRaw};
+use super::{Input, InputBuilder, InputRaw};
 use crate::{
     messages::{LinkMessage, Payload},
     traits::SendSyncAny,
@@ -49,11 +49,13 @@ fn test_typed_input<T: Send + Sync + Clone + std::fmt::Debug + PartialEq + 'stat
     let input_raw = InputRaw {
         port_id: "test-id".into(),
         receiver: rx,
+        coalescing: false,
     };
 
     let input = Input {
         input_raw,
         deserializer: Arc::new(deserializer),
+        default: None,
     };
 
     let message = LinkMessage::new(
@@ -144,3 +146,172 @@ fn test_protobuf_prost() {
         <TestProto>::decode(bytes).map_err(|e| anyhow::anyhow!(e))
     })
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+/// DEFAULT VALUE
+
+/// Test that an input with a default value produces it when nothing was received, while an input
+/// without a default value does not.
+///
+/// ## Scenario tested
+///
+/// An operator has two inputs, only the second one has a default value. Nothing was produced on
+/// the second input while a message was sent on the first.
+#[test]
+fn test_default_value() {
+    let hlc = uhlc::HLC::default();
+    let deserializer =
+        |bytes: &[u8]| serde_json::de::from_slice::<u64>(bytes).map_err(|e| anyhow::anyhow!(e));
+
+    let (tx_first, rx_first) = flume::unbounded::<LinkMessage>();
+    let input_first = InputBuilder {
+        port_id: "first".into(),
+        receiver: rx_first,
+    }
+    .typed(deserializer);
+
+    let (tx_second, rx_second) = flume::unbounded::<LinkMessage>();
+    let input_second = InputBuilder {
+        port_id: "second".into(),
+        receiver: rx_second,
+    }
+    .typed(deserializer)
+    .with_default(42);
+
+    assert!(input_first
+        .try_recv_or_default()
+        .expect("Channel should not be disconnected")
+        .is_none());
+
+    let (data, timestamp) = input_second
+        .try_recv_or_default()
+        .expect("Channel should not be disconnected")
+        .expect("The default value should have been returned");
+    assert_eq!(42, *data);
+    assert!(timestamp.is_none());
+
+    tx_first
+        .send(LinkMessage::new(
+            Payload::Bytes(Arc::new(b"1".to_vec())),
+            hlc.new_timestamp(),
+        ))
+        .expect("Failed to send message");
+    let (data, timestamp) = input_first
+        .try_recv_or_default()
+        .expect("Channel should not be disconnected")
+        .expect("No message was received");
+    assert_eq!(1, *data);
+    assert!(timestamp.is_some());
+
+    // Once the second input produces, the received value takes precedence over the default one.
+    tx_second
+        .send(LinkMessage::new(
+            Payload::Bytes(Arc::new(b"2".to_vec())),
+            hlc.new_timestamp(),
+        ))
+        .expect("Failed to send message");
+    let (data, timestamp) = input_second
+        .try_recv_or_default()
+        .expect("Channel should not be disconnected")
+        .expect("No message was received");
+    assert_eq!(2, *data);
+    assert!(timestamp.is_some());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+/// COALESCING
+
+/// Test that an input in coalescing mode only produces the most recent message.
+///
+/// ## Scenario tested
+///
+/// Ten messages are sent in a burst on a coalescing input before the operator processes it: only
+/// the last one is received, the others are discarded.
+#[test]
+fn test_coalescing() {
+    let hlc = uhlc::HLC::default();
+    let (tx, rx) = flume::unbounded::<LinkMessage>();
+    let input = InputBuilder {
+        port_id: "coalescing".into(),
+        receiver: rx,
+    }
+    .typed(|bytes| serde_json::de::from_slice::<u64>(bytes).map_err(|e| anyhow::anyhow!(e)))
+    .coalescing();
+
+    for i in 0u64..10 {
+        tx.send(LinkMessage::new(
+            Payload::Bytes(Arc::new(serde_json::ser::to_vec(&i).unwrap())),
+            hlc.new_timestamp(),
+        ))
+        .expect("Failed to send message");
+    }
+
+    let (data, _) = input
+        .try_recv()
+        .expect("Channel should not be disconnected")
+        .expect("A message should have been received");
+    assert_eq!(9, *data);
+    assert!(input
+        .try_recv()
+        .expect("Channel should not be disconnected")
+        .is_none());
+
+    tx.send(LinkMessage::new(
+        Payload::Bytes(Arc::new(serde_json::ser::to_vec(&10u64).unwrap())),
+        hlc.new_timestamp(),
+    ))
+    .expect("Failed to send message");
+    let (data, _) = input
+        .try_recv()
+        .expect("Channel should not be disconnected")
+        .expect("A message should have been received");
+    assert_eq!(10, *data);
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+/// Test that a trait object can be exchanged between nodes running on the same runtime.
+///
+/// ## Scenario tested
+///
+/// A `Box<dyn Shape>` is sent as a Payload::Typed on an input typed with that same trait object.
+/// The received data dereferences to the trait object, on which a method of the trait is called.
+#[test]
+fn test_trait_object() {
+    type DynShape = Box<dyn Shape + Send + Sync>;
+
+    let hlc = uhlc::HLC::default();
+    let (tx, rx) = flume::unbounded::<LinkMessage>();
+    let input = InputBuilder {
+        port_id: "shapes".into(),
+        receiver: rx,
+    }
+    .typed::<DynShape>(|_bytes| Err(anyhow::anyhow!("Trait objects cannot be deserialised")));
+
+    let shape: DynShape = Box::new(Square(2.0));
+    tx.send(LinkMessage::new(
+        Payload::Typed((
+            Arc::new(shape) as Arc<dyn SendSyncAny>,
+            // The serialiser should never be called, hence the panic.
+            Arc::new(|_buffer, _data| panic!("Unexpected call to serialise the data")),
+        )),
+        hlc.new_timestamp(),
+    ))
+    .expect("Failed to send message");
+
+    let (data, _) = input
+        .try_recv()
+        .expect("Channel should not be disconnected")
+        .expect("A message should have been received");
+    assert_eq!(4.0, data.area());
+}
@@ -246,6 +246,18 @@ impl LinkMessage {
 ///
 /// To perform the deserialisation, the [deserialiser](crate::io::InputBuilder::typed()) function passed to the
 /// [`Input<T>`](crate::prelude::Input) will be called.
+///
+/// # Trait objects
+///
+/// Typed data is retrieved by downcasting it, through [Any](std::any::Any), to the *exact* type that was sent: data
+/// sent as a `Square` cannot be retrieved as a `dyn Shape`, even if `Square` implements `Shape`.
+///
+/// To exchange trait objects, the trait object itself should be used as the type of both the
+/// [`Output<T>`](crate::prelude::Output) and the [`Input<T>`](crate::prelude::Input), for instance
+/// `Box<dyn Shape + Send + Sync>`. A `Data<Box<dyn Shape + Send + Sync>>` then dereferences to the boxed trait object.
+///
+/// Note that, as trait objects cannot be deserialised, such data can only be exchanged between nodes running on the
+/// same Zenoh-Flow runtime.
 #[derive(Debug)]
 pub struct Data<T> {
     inner: DataInner<T>,
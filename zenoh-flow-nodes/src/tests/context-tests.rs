@@ -0,0 +1,78 @@
+//
+// Copyright (c) 2021 - 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use std::sync::{Arc, Mutex};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use zenoh_flow_commons::{InstanceId, RuntimeId};
+
+use super::Context;
+
+/// A logger keeping the target, level and message of the records it receives.
+struct RecordingLogger {
+    records: Mutex<Vec<(String, Level, String)>>,
+}
+
+impl Log for RecordingLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        self.records.lock().unwrap().push((
+            record.target().to_string(),
+            record.level(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RecordingLogger = RecordingLogger {
+    records: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn test_log() {
+    log::set_logger(&LOGGER).expect("Failed to set logger");
+    log::set_max_level(LevelFilter::Trace);
+
+    let instance_id = InstanceId::from(uuid::Uuid::new_v4());
+    let context = Context::new(
+        Arc::from("test-flow"),
+        instance_id.clone(),
+        RuntimeId::rand(),
+    );
+    context.log(Level::Warn, "before the node id is set");
+    context
+        .with_node_id("node-0".into())
+        .log(Level::Debug, "after the node id is set");
+
+    assert_eq!(
+        vec![
+            (
+                format!("zenoh_flow::{}::?", instance_id),
+                Level::Warn,
+                "before the node id is set".to_string()
+            ),
+            (
+                format!("zenoh_flow::{}::node-0", instance_id),
+                Level::Debug,
+                "after the node id is set".to_string()
+            ),
+        ],
+        *LOGGER.records.lock().unwrap()
+    );
+}
@@ -16,6 +16,7 @@ use crate::context::Context;
 use crate::io::{Inputs, Outputs};
 use async_trait::async_trait;
 use std::any::Any;
+use std::time::Duration;
 use zenoh_flow_commons::{Configuration, Result};
 
 /// The `SendSyncAny` trait allows Zenoh-Flow to send data between nodes running in the same process without
@@ -62,6 +63,16 @@ impl<T: 'static + Send + Sync> SendSyncAny for T {
 /// Note that the `on_resume` hook is only run once the node has been aborted. It is not run when it is created.
 ///
 /// A default blank implementation is provided.
+///
+/// # Live reconfiguration: `on_configuration_update`
+///
+/// A node can react to a change of its [Configuration] while it is loaded. A default implementation that ignores the
+/// new configuration is provided.
+///
+/// # Self-description: `description`, `documentation_url`
+///
+/// A node can describe itself such that management tools can display what it does. Default implementations returning
+/// no information are provided.
 #[async_trait]
 pub trait Node: Send + Sync {
     /// The code a Zenoh-Flow runtime will execute in a loop.
@@ -87,6 +98,45 @@ pub trait Node: Send + Sync {
     }
 
     async fn on_abort(&self) {}
+
+    /// The maximum duration Zenoh-Flow will wait for [on_abort](Node::on_abort()) to complete.
+    ///
+    /// Once this duration has elapsed, the call to `on_abort` is cancelled, at its nearest next `.await` point, such
+    /// that a misbehaving node cannot prevent a data flow from being aborted.
+    ///
+    /// The blanket implementation defaults to returning 5 seconds.
+    fn graceful_shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
+    /// Custom code that Zenoh-Flow will run when the [Configuration] of a node is updated while it is loaded.
+    ///
+    /// This hook allows changing parameters of a node (e.g. a threshold) without re-creating the data flow. As it
+    /// takes `&self`, a node that supports it has to keep its parameters behind a synchronisation primitive.
+    ///
+    /// Returning an error signals that the new configuration is rejected: Zenoh-Flow will keep the previous one.
+    ///
+    /// The blanket implementation defaults to returning `Ok(())`, i.e. the new configuration is accepted but ignored.
+    async fn on_configuration_update(&self, _configuration: &Configuration) -> Result<()> {
+        Ok(())
+    }
+
+    /// A human-readable description of what this node does.
+    ///
+    /// This description is intended for management tools: it allows a node loaded from a shared library to describe
+    /// itself without relying on an external documentation.
+    ///
+    /// The blanket implementation defaults to returning an empty string.
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// The URL where the documentation of this node can be found, if any.
+    ///
+    /// The blanket implementation defaults to returning `None`.
+    fn documentation_url(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// A `Source` feeds data into a data flow.
@@ -159,6 +209,12 @@ pub trait Source: Node + Send + Sync {
 ///
 /// A structure implementing the `Operator` trait typically needs to keep a reference to its `Input`(s) and `Output`(s).
 ///
+/// ## Filtering
+///
+/// An `Operator` is not required to send data on its `Output`(s) at every [iteration](Node::iteration()): an iteration
+/// that consumes its inputs and returns `Ok(())` without sending anything is legitimate. This is how a filter is
+/// implemented, the data received is simply dropped. The Zenoh-Flow runtime will call the next iteration as usual.
+///
 /// ## Example
 ///
 /// ```no_run
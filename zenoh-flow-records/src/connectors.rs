@@ -15,6 +15,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use zenoh_flow_commons::NodeId;
+use zenoh_flow_descriptors::{CongestionControl, Reliability};
 use zenoh_keyexpr::OwnedKeyExpr;
 
 /// A `SenderRecord` describes the sending end of a "Zenoh connection" between Zenoh-Flow runtimes.
@@ -28,6 +29,8 @@ use zenoh_keyexpr::OwnedKeyExpr;
 pub struct SenderRecord {
     pub(crate) id: NodeId,
     pub(crate) resource: OwnedKeyExpr,
+    #[serde(default)]
+    pub(crate) congestion_control: CongestionControl,
 }
 
 impl Display for SenderRecord {
@@ -44,6 +47,11 @@ impl SenderRecord {
     pub fn resource(&self) -> &OwnedKeyExpr {
         &self.resource
     }
+
+    /// Returns the [CongestionControl] of the Zenoh publications performed by this `Sender`.
+    pub fn congestion_control(&self) -> CongestionControl {
+        self.congestion_control
+    }
 }
 
 /// A `ReceiverRecord` describes the receiving end of a "Zenoh connection" between Zenoh-Flow runtimes.
@@ -57,6 +65,8 @@ impl SenderRecord {
 pub struct ReceiverRecord {
     pub(crate) id: NodeId,
     pub(crate) resource: OwnedKeyExpr,
+    #[serde(default)]
+    pub(crate) reliability: Reliability,
 }
 
 impl Display for ReceiverRecord {
@@ -77,4 +87,9 @@ impl ReceiverRecord {
     pub fn resource(&self) -> &OwnedKeyExpr {
         &self.resource
     }
+
+    /// Returns the [Reliability] of the Zenoh subscriber declared by this `Receiver`.
+    pub fn reliability(&self) -> Reliability {
+        self.reliability
+    }
 }
@@ -20,7 +20,7 @@ use std::{
     sync::Arc,
 };
 use uuid::Uuid;
-use zenoh_flow_commons::{InstanceId, NodeId, Result, RuntimeId};
+use zenoh_flow_commons::{Configuration, InstanceId, NodeId, Result, RuntimeId};
 use zenoh_flow_descriptors::{
     FlattenedDataFlowDescriptor, FlattenedOperatorDescriptor, FlattenedSinkDescriptor,
     FlattenedSourceDescriptor, InputDescriptor, LinkDescriptor, OutputDescriptor,
@@ -30,6 +30,30 @@ use zenoh_keyexpr::OwnedKeyExpr;
 const SENDER_SUFFIX: &str = "__zenoh_flow_sender";
 const RECEIVER_SUFFIX: &str = "__zenoh_flow_receiver";
 
+/// The kind of a node in a [DataFlowRecord].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Source,
+    Operator,
+    Sink,
+    /// A node, added when creating the record, that sends data to a node managed by another Zenoh-Flow runtime.
+    Sender,
+    /// A node, added when creating the record, that receives data from a node managed by another Zenoh-Flow runtime.
+    Receiver,
+}
+
+impl std::fmt::Display for NodeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeKind::Source => write!(f, "Source"),
+            NodeKind::Operator => write!(f, "Operator"),
+            NodeKind::Sink => write!(f, "Sink"),
+            NodeKind::Sender => write!(f, "Sender"),
+            NodeKind::Receiver => write!(f, "Receiver"),
+        }
+    }
+}
+
 /// A `DataFlowRecord` represents a single deployment of a [FlattenedDataFlowDescriptor] on an infrastructure, i.e. on a
 /// set of Zenoh-Flow runtimes.
 ///
@@ -73,6 +97,9 @@ impl DataFlowRecord {
     ///
     /// Node that this should not happen if the [FlattenedDataFlowDescriptor] was obtained by parsing and flattening a
     /// [DataFlowDescriptor](zenoh_flow_descriptors::DataFlowDescriptor).
+    ///
+    /// If a namespace was provided, it is prepended to these key expressions. Creating the record will fail if the
+    /// namespace is not a valid key expression or if it contains wildcards.
     pub fn try_new(
         data_flow: &FlattenedDataFlowDescriptor,
         default_runtime: &RuntimeId,
@@ -80,19 +107,38 @@ impl DataFlowRecord {
         let FlattenedDataFlowDescriptor {
             id,
             name,
+            namespace,
             sources,
             operators,
             sinks,
             mut links,
             mut mapping,
+            allow_cycles: _,
         } = data_flow.clone();
 
         let id = id.unwrap_or_else(|| Uuid::new_v4().into());
 
+        let prefix = match namespace {
+            Some(namespace) => {
+                let namespace = OwnedKeyExpr::autocanonize(namespace.to_string()).map_err(|e| {
+                    anyhow!(
+                        "The namespace < {} > is not a valid key expression: {:?}",
+                        namespace,
+                        e
+                    )
+                })?;
+                if namespace.is_wild() {
+                    bail!("The namespace < {} > cannot contain wildcards", namespace);
+                }
+                format!("{}/{}", namespace, id)
+            }
+            None => id.to_string(),
+        };
+
         // Nodes that are not running on the same runtime need to be connected.
         let mut additional_links = Vec::default();
-        let mut receivers = HashMap::default();
-        let mut senders = HashMap::default();
+        let mut receivers: HashMap<NodeId, ReceiverRecord> = HashMap::default();
+        let mut senders: HashMap<NodeId, SenderRecord> = HashMap::default();
 
         let mut default_mapping_if_unassigned = |node_id: &NodeId| {
             for (_, nodes) in mapping.iter() {
@@ -155,7 +201,7 @@ Is its name valid (i.e. does it reference an actual node)?
                 .context(format!("Failed to process link:\n{}", link))?;
 
             if runtime_from != runtime_to {
-                let key_expr_str = format!("{}/{}/{}", id, link.from.node, link.from.output);
+                let key_expr_str = format!("{}/{}/{}", prefix, link.from.node, link.from.output);
                 let key_expression =
                     OwnedKeyExpr::autocanonize(key_expr_str.clone()).map_err(|e| {
                         // NOTE: This error should not happen as we ensure that (i) all node ids and port ids are valid
@@ -180,6 +226,32 @@ Caused by:
                 let sender_id: NodeId = format!("{}{SENDER_SUFFIX}", link.from.node).into();
                 let receiver_id: NodeId = format!("{}{RECEIVER_SUFFIX}", link.to.node).into();
 
+                // NOTE: The Sender (resp. Receiver) is shared by all the links that leave (resp. reach) the node, their
+                // settings must thus agree.
+                if let Some(sender) = senders.get(&sender_id) {
+                    if sender.congestion_control != link.congestion_control {
+                        bail!(
+                            "The links leaving the node < {} > towards other runtimes declare different congestion \
+                             controls: {:?} and {:?}",
+                            link.from.node,
+                            sender.congestion_control,
+                            link.congestion_control
+                        );
+                    }
+                }
+
+                if let Some(receiver) = receivers.get(&receiver_id) {
+                    if receiver.reliability != link.reliability {
+                        bail!(
+                            "The links reaching the node < {} > from other runtimes declare different reliabilities: \
+                             {:?} and {:?}",
+                            link.to.node,
+                            receiver.reliability,
+                            link.reliability
+                        );
+                    }
+                }
+
                 let mut input = InputDescriptor {
                     node: sender_id.clone(),
                     input: key_expression.to_string().into(),
@@ -193,6 +265,8 @@ Caused by:
                 additional_links.push(LinkDescriptor {
                     from: output,
                     to: input,
+                    reliability: link.reliability,
+                    congestion_control: link.congestion_control,
                     #[cfg(feature = "shared-memory")]
                     shared_memory: link.shared_memory,
                 });
@@ -202,6 +276,7 @@ Caused by:
                     SenderRecord {
                         id: sender_id.clone(),
                         resource: key_expression.clone(),
+                        congestion_control: link.congestion_control,
                     },
                 );
                 additional_mappings
@@ -214,6 +289,7 @@ Caused by:
                     ReceiverRecord {
                         id: receiver_id.clone(),
                         resource: key_expression,
+                        reliability: link.reliability,
                     },
                 );
                 additional_mappings
@@ -308,6 +384,180 @@ Caused by:
     pub fn sinks(&self) -> &HashMap<NodeId, FlattenedSinkDescriptor> {
         &self.sinks
     }
+
+    /// Returns the [kind](NodeKind) of the node identified by `node`, or `None` if there is no such node in the data
+    /// flow.
+    pub fn node_kind(&self, node: &NodeId) -> Option<NodeKind> {
+        if self.sources.contains_key(node) {
+            Some(NodeKind::Source)
+        } else if self.operators.contains_key(node) {
+            Some(NodeKind::Operator)
+        } else if self.sinks.contains_key(node) {
+            Some(NodeKind::Sink)
+        } else if self.senders.contains_key(node) {
+            Some(NodeKind::Sender)
+        } else if self.receivers.contains_key(node) {
+            Some(NodeKind::Receiver)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the group the Source, Operator or Sink identified by `node` is part of, or `None` if it is not part of
+    /// any group or if there is no such node in the data flow.
+    pub fn node_group(&self, node: &NodeId) -> Option<&Arc<str>> {
+        self.sources
+            .get(node)
+            .and_then(|source| source.group.as_ref())
+            .or_else(|| {
+                self.operators
+                    .get(node)
+                    .and_then(|operator| operator.group.as_ref())
+            })
+            .or_else(|| self.sinks.get(node).and_then(|sink| sink.group.as_ref()))
+    }
+
+    /// Returns the nodes that are part of the provided `group`, sorted by identifier.
+    ///
+    /// The [Senders](SenderRecord) and [Receivers](ReceiverRecord) connecting these nodes to nodes managed by other
+    /// Zenoh-Flow runtimes are included: without them, the nodes of the group could not communicate.
+    pub fn group_members(&self, group: &str) -> Vec<NodeId> {
+        let mut members = self
+            .sources
+            .keys()
+            .chain(self.operators.keys())
+            .chain(self.sinks.keys())
+            .filter(|&node| self.node_group(node).is_some_and(|g| g.as_ref() == group))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let connectors = self
+            .links
+            .iter()
+            .filter_map(|link| {
+                if self.senders.contains_key(&link.to.node) && members.contains(&link.from.node) {
+                    Some(link.to.node.clone())
+                } else if self.receivers.contains_key(&link.from.node)
+                    && members.contains(&link.to.node)
+                {
+                    Some(link.from.node.clone())
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        members.extend(connectors);
+        members.sort_by(|left, right| left.as_ref().cmp(right.as_ref()));
+        members.dedup();
+
+        members
+    }
+
+    /// Returns the representation of this data flow in the [DOT] language, to visualise it with Graphviz.
+    ///
+    /// Each node is labelled with its identifier and its [kind](NodeKind) and each link with the identifiers of the
+    /// output and input it connects. The nodes are sorted by identifier and the links follow their order in the record.
+    /// The nodes that are part of a [group](DataFlowRecord::node_group()) are placed in a cluster named after it.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn export_dot(&self) -> String {
+        fn escape(id: &str) -> String {
+            id.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+
+        let mut nodes = self
+            .sources
+            .keys()
+            .chain(self.operators.keys())
+            .chain(self.sinks.keys())
+            .chain(self.senders.keys())
+            .chain(self.receivers.keys())
+            .collect::<Vec<_>>();
+        nodes.sort_by(|left, right| left.as_ref().cmp(right.as_ref()));
+
+        let mut groups = nodes
+            .iter()
+            .filter_map(|&node| self.node_group(node))
+            .collect::<Vec<_>>();
+        groups.sort();
+        groups.dedup();
+
+        let node_statement = |node: &NodeId, indent: &str| -> String {
+            match self.node_kind(node) {
+                Some(kind) => format!(
+                    "{indent}\"{0}\" [label=\"{0}\\n({1})\"];\n",
+                    escape(node),
+                    kind
+                ),
+                None => String::default(),
+            }
+        };
+
+        let mut dot = format!("digraph \"{}\" {{\n", escape(&self.name));
+        for group in groups {
+            dot.push_str(&format!(
+                "    subgraph \"cluster_{0}\" {{\n        label=\"{0}\";\n",
+                escape(group)
+            ));
+            for node in nodes
+                .iter()
+                .filter(|&&node| self.node_group(node) == Some(group))
+            {
+                dot.push_str(&node_statement(node, "        "));
+            }
+            dot.push_str("    }\n");
+        }
+
+        for node in nodes
+            .iter()
+            .filter(|&&node| self.node_group(node).is_none())
+        {
+            dot.push_str(&node_statement(node, "    "));
+        }
+
+        for link in &self.links {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{} -> {}\"];\n",
+                escape(&link.from.node),
+                escape(&link.to.node),
+                escape(&link.from.output),
+                escape(&link.to.input)
+            ));
+        }
+        dot.push('}');
+
+        dot
+    }
+
+    /// Returns the [Configuration] of the Source, Operator or Sink identified by `node`, or `None` if there is no
+    /// such node in the data flow.
+    ///
+    /// This is the configuration that was (or will be) passed to the constructor of the node.
+    pub fn node_configuration(&self, node: &NodeId) -> Option<&Configuration> {
+        self.sources
+            .get(node)
+            .map(|source| &source.configuration)
+            .or_else(|| {
+                self.operators
+                    .get(node)
+                    .map(|operator| &operator.configuration)
+            })
+            .or_else(|| self.sinks.get(node).map(|sink| &sink.configuration))
+    }
+
+    /// Returns a mutable reference to the [Configuration] of the Source, Operator or Sink identified by `node`, or
+    /// `None` if there is no such node in the data flow.
+    pub fn node_configuration_mut(&mut self, node: &NodeId) -> Option<&mut Configuration> {
+        if let Some(source) = self.sources.get_mut(node) {
+            return Some(&mut source.configuration);
+        }
+
+        if let Some(operator) = self.operators.get_mut(node) {
+            return Some(&mut operator.configuration);
+        }
+
+        self.sinks.get_mut(node).map(|sink| &mut sink.configuration)
+    }
 }
 
 #[cfg(test)]
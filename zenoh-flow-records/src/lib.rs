@@ -26,7 +26,7 @@
 //! [Zenoh-Flow](https://github.com/eclipse-zenoh/zenoh-flow) project.
 
 mod dataflow;
-pub use dataflow::DataFlowRecord;
+pub use dataflow::{DataFlowRecord, NodeKind};
 
 mod connectors;
 pub use connectors::{ReceiverRecord, SenderRecord};
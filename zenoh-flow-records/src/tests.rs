@@ -16,12 +16,12 @@ use std::collections::{HashMap, HashSet};
 
 use crate::{
     dataflow::{RECEIVER_SUFFIX, SENDER_SUFFIX},
-    DataFlowRecord, ReceiverRecord, SenderRecord,
+    DataFlowRecord, NodeKind, ReceiverRecord, SenderRecord,
 };
 use zenoh_flow_commons::{NodeId, RuntimeId, Vars};
 use zenoh_flow_descriptors::{
-    DataFlowDescriptor, FlattenedDataFlowDescriptor, InputDescriptor, LinkDescriptor,
-    OutputDescriptor,
+    CongestionControl, DataFlowDescriptor, FlattenedDataFlowDescriptor, InputDescriptor,
+    LinkDescriptor, OutputDescriptor, Reliability,
 };
 use zenoh_keyexpr::OwnedKeyExpr;
 
@@ -190,6 +190,8 @@ links:
     to:
      node: operator-1
      input: in-1
+    reliability: reliable
+    congestion_control: block
 
   - from:
      node: operator-1
@@ -227,6 +229,7 @@ mapping:
         Some(&SenderRecord {
             id: sender_thing_edge.clone(),
             resource: key_expr_thing_edge.clone(),
+            congestion_control: CongestionControl::Block,
         }),
         record.senders.get(&sender_thing_edge)
     );
@@ -234,6 +237,7 @@ mapping:
         Some(&ReceiverRecord {
             id: receiver_thing_edge.clone(),
             resource: key_expr_thing_edge.clone(),
+            reliability: Reliability::Reliable,
         }),
         record.receivers.get(&receiver_thing_edge)
     );
@@ -246,6 +250,7 @@ mapping:
         Some(&SenderRecord {
             id: sender_edge_default.clone(),
             resource: key_expr_edge_default.clone(),
+            congestion_control: CongestionControl::Drop,
         }),
         record.senders.get(&sender_edge_default)
     );
@@ -253,6 +258,7 @@ mapping:
         Some(&ReceiverRecord {
             id: receiver_edge_default.clone(),
             resource: key_expr_edge_default.clone(),
+            reliability: Reliability::BestEffort,
         }),
         record.receivers.get(&receiver_edge_default)
     );
@@ -267,6 +273,8 @@ mapping:
             node: sender_thing_edge.clone(),
             input: key_expr_thing_edge.to_string().into(),
         },
+        reliability: Reliability::Reliable,
+        congestion_control: CongestionControl::Block,
         #[cfg(feature = "shared-memory")]
         shared_memory: None,
     };
@@ -281,6 +289,8 @@ mapping:
             node: "operator-1".into(),
             input: "in-1".into(),
         },
+        reliability: Reliability::Reliable,
+        congestion_control: CongestionControl::Block,
         #[cfg(feature = "shared-memory")]
         shared_memory: None,
     };
@@ -295,6 +305,8 @@ mapping:
             node: sender_edge_default.clone(),
             input: key_expr_edge_default.to_string().into(),
         },
+        reliability: Reliability::BestEffort,
+        congestion_control: CongestionControl::Drop,
         #[cfg(feature = "shared-memory")]
         shared_memory: None,
     };
@@ -309,11 +321,27 @@ mapping:
             node: "sink-2".into(),
             input: "in-2".into(),
         },
+        reliability: Reliability::BestEffort,
+        congestion_control: CongestionControl::Drop,
         #[cfg(feature = "shared-memory")]
         shared_memory: None,
     };
     assert!(record.links.contains(&link_default));
 
+    // assert the kinds
+    assert_eq!(Some(NodeKind::Source), record.node_kind(&"source-0".into()));
+    assert_eq!(
+        Some(NodeKind::Operator),
+        record.node_kind(&"operator-1".into())
+    );
+    assert_eq!(Some(NodeKind::Sink), record.node_kind(&"sink-2".into()));
+    assert_eq!(Some(NodeKind::Sender), record.node_kind(&sender_thing_edge));
+    assert_eq!(
+        Some(NodeKind::Receiver),
+        record.node_kind(&receiver_thing_edge)
+    );
+    assert_eq!(None, record.node_kind(&"unknown".into()));
+
     // assert the mapping
     assert_eq!(
         HashMap::from([
@@ -394,3 +422,419 @@ links:
     let _string = serde_yaml::to_string(&record).expect("Failed to serialize to yaml");
     println!("{_string}");
 }
+
+#[test]
+fn test_namespace() {
+    let runtime_thing = RuntimeId::rand();
+    let default_runtime = RuntimeId::rand();
+
+    let desc = format!(
+        r#"
+id: 5f7d9e4a-6a86-4a4b-9d5a-4e06b4d1a0d0
+name: namespaced flow
+namespace: NAMESPACE
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-1
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-1
+
+links:
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: sink-1
+     input: in-1
+
+mapping:
+  {0}:
+    - source-0
+"#,
+        runtime_thing
+    );
+
+    let try_record = |namespace: &str| {
+        let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+            serde_yaml::from_str::<DataFlowDescriptor>(&desc.replace("NAMESPACE", namespace))
+                .unwrap(),
+            Vars::default(),
+        )
+        .unwrap();
+        DataFlowRecord::try_new(&flat_desc, &default_runtime)
+    };
+
+    let record_a = try_record("deployment/a").expect("Failed to create record");
+    let record_b = try_record("deployment/b").expect("Failed to create record");
+    assert_eq!(record_a.instance_id(), record_b.instance_id());
+
+    let sender: NodeId = format!("source-0{}", SENDER_SUFFIX).into();
+    let resource_a = &record_a.senders.get(&sender).unwrap().resource;
+    let resource_b = &record_b.senders.get(&sender).unwrap().resource;
+    assert_eq!(
+        format!("deployment/a/{}/source-0/out-0", record_a.instance_id()),
+        resource_a.to_string()
+    );
+    assert!(!resource_a.intersects(resource_b));
+
+    assert!(try_record("deployment/*").is_err());
+}
+
+#[test]
+fn test_node_configuration() {
+    let desc = r#"
+name: configured flow
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    configuration:
+      rate: 10
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-1
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-1
+
+links:
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: sink-1
+     input: in-1
+"#;
+
+    let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str::<DataFlowDescriptor>(desc).unwrap(),
+        Vars::default(),
+    )
+    .unwrap();
+    let record = DataFlowRecord::try_new(&flat_desc, &RuntimeId::rand()).unwrap();
+
+    let configuration = record
+        .node_configuration(&"source-0".into())
+        .expect("Missing configuration of source-0");
+    assert_eq!(10, configuration.get_u64("rate").unwrap());
+    assert!(record.node_configuration(&"sink-1".into()).is_some());
+    assert!(record.node_configuration(&"unknown".into()).is_none());
+}
+
+#[test]
+fn test_export_dot() {
+    let desc = r#"
+name: dot flow
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-1
+    library: file:///home/zenoh-flow/liboperator.so
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-2
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-2
+
+links:
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: operator-1
+     input: in-1
+
+  - from:
+     node: operator-1
+     output: out-1
+    to:
+     node: sink-2
+     input: in-2
+"#;
+
+    let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str::<DataFlowDescriptor>(desc).unwrap(),
+        Vars::default(),
+    )
+    .unwrap();
+    let record = DataFlowRecord::try_new(&flat_desc, &RuntimeId::rand()).unwrap();
+
+    assert_eq!(
+        r#"digraph "dot flow" {
+    "operator-1" [label="operator-1\n(Operator)"];
+    "sink-2" [label="sink-2\n(Sink)"];
+    "source-0" [label="source-0\n(Source)"];
+    "source-0" -> "operator-1" [label="out-0 -> in-1"];
+    "operator-1" -> "sink-2" [label="out-1 -> in-2"];
+}"#,
+        record.export_dot()
+    );
+}
+
+#[test]
+fn test_export_dot_escape() {
+    let desc = r#"
+name: 'the "escaped" flow'
+
+sources:
+  - id: 'source\'
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-1
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-1
+
+links:
+  - from:
+     node: 'source\'
+     output: out-0
+    to:
+     node: sink-1
+     input: in-1
+"#;
+
+    let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str::<DataFlowDescriptor>(desc).unwrap(),
+        Vars::default(),
+    )
+    .unwrap();
+    let record = DataFlowRecord::try_new(&flat_desc, &RuntimeId::rand()).unwrap();
+
+    assert_eq!(
+        r#"digraph "the \"escaped\" flow" {
+    "sink-1" [label="sink-1\n(Sink)"];
+    "source\\" [label="source\\\n(Source)"];
+    "source\\" -> "sink-1" [label="out-0 -> in-1"];
+}"#,
+        record.export_dot()
+    );
+}
+
+#[test]
+fn test_groups() {
+    let runtime_thing = RuntimeId::rand();
+    let runtime_edge = RuntimeId::rand();
+
+    let desc = format!(
+        r#"
+name: grouped flow
+
+sources:
+  - id: source-0
+    group: acquisition
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+operators:
+  - id: operator-1
+    group: processing
+    library: file:///home/zenoh-flow/liboperator.so
+    inputs:
+      - in-1
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-2
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-2
+
+links:
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: operator-1
+     input: in-1
+
+  - from:
+     node: operator-1
+     output: out-1
+    to:
+     node: sink-2
+     input: in-2
+
+mapping:
+  {}:
+    - source-0
+  {}:
+    - operator-1
+    - sink-2
+"#,
+        runtime_thing, runtime_edge
+    );
+
+    let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+        serde_yaml::from_str::<DataFlowDescriptor>(&desc).unwrap(),
+        Vars::default(),
+    )
+    .unwrap();
+    let record = DataFlowRecord::try_new(&flat_desc, &RuntimeId::rand()).unwrap();
+
+    assert_eq!(
+        Some(&"processing".into()),
+        record.node_group(&"operator-1".into())
+    );
+    assert!(record.node_group(&"sink-2".into()).is_none());
+
+    let sender: NodeId = format!("source-0{SENDER_SUFFIX}").into();
+    let receiver: NodeId = format!("operator-1{RECEIVER_SUFFIX}").into();
+    assert_eq!(
+        vec![NodeId::from("source-0"), sender.clone()],
+        record.group_members("acquisition")
+    );
+    assert_eq!(
+        vec![NodeId::from("operator-1"), receiver.clone()],
+        record.group_members("processing")
+    );
+    assert!(record.group_members("unknown").is_empty());
+
+    assert_eq!(
+        format!(
+            r#"digraph "grouped flow" {{
+    subgraph "cluster_acquisition" {{
+        label="acquisition";
+        "source-0" [label="source-0\n(Source)"];
+    }}
+    subgraph "cluster_processing" {{
+        label="processing";
+        "operator-1" [label="operator-1\n(Operator)"];
+    }}
+    "{receiver}" [label="{receiver}\n(Receiver)"];
+    "sink-2" [label="sink-2\n(Sink)"];
+    "{sender}" [label="{sender}\n(Sender)"];
+"#
+        ),
+        record
+            .export_dot()
+            .lines()
+            .take(12)
+            .map(|line| format!("{line}\n"))
+            .collect::<String>()
+    );
+}
+
+#[test]
+fn test_conflicting_link_settings() {
+    let runtime_thing = RuntimeId::rand();
+    let runtime_edge = RuntimeId::rand();
+
+    let desc = format!(
+        r#"
+name: conflicting flow
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+  - id: source-1
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-1
+
+sinks:
+  - id: sink-2
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-2-0
+      - in-2-1
+
+  - id: sink-3
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-3
+
+links:
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: sink-2
+     input: in-2-0
+    reliability: reliable
+    congestion_control: block
+
+  - from:
+     node: source-1
+     output: out-1
+    to:
+     node: sink-2
+     input: in-2-1
+    reliability: RELIABILITY
+
+  - from:
+     node: source-0
+     output: out-0
+    to:
+     node: sink-3
+     input: in-3
+    reliability: reliable
+    congestion_control: CONGESTION_CONTROL
+
+mapping:
+  {}:
+    - source-0
+    - source-1
+  {}:
+    - sink-2
+    - sink-3
+"#,
+        runtime_thing, runtime_edge
+    );
+
+    let try_record = |reliability: &str, congestion_control: &str| {
+        let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+            serde_yaml::from_str::<DataFlowDescriptor>(
+                &desc
+                    .replace("RELIABILITY", reliability)
+                    .replace("CONGESTION_CONTROL", congestion_control),
+            )
+            .unwrap(),
+            Vars::default(),
+        )
+        .unwrap();
+        DataFlowRecord::try_new(&flat_desc, &RuntimeId::rand())
+    };
+
+    let record = try_record("reliable", "block").expect("Failed to create record");
+    let receiver: NodeId = format!("sink-2{RECEIVER_SUFFIX}").into();
+    assert_eq!(
+        Reliability::Reliable,
+        record.receivers.get(&receiver).unwrap().reliability
+    );
+
+    // The links reaching `sink-2` share the same Receiver.
+    let error = try_record("best_effort", "block").expect_err("Conflicting reliabilities");
+    assert!(error.to_string().contains("sink-2"));
+
+    // The links leaving `source-0` share the same Sender.
+    let error = try_record("reliable", "drop").expect_err("Conflicting congestion controls");
+    assert!(error.to_string().contains("source-0"));
+}
@@ -14,11 +14,21 @@
 
 use crate::runners::Runner;
 
-use std::{collections::HashMap, fmt::Display, ops::Deref};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
+use anyhow::{bail, Context};
+use flume::{Receiver, Sender};
 use serde::{Deserialize, Serialize};
 use uhlc::{Timestamp, HLC};
-use zenoh_flow_commons::{NodeId, Result, RuntimeId};
+use zenoh_flow_commons::{Configuration, NodeId, Result, RuntimeId};
 use zenoh_flow_records::DataFlowRecord;
 
 /// A `DataFlowInstance` keeps track of the parts of a data flow managed by the Zenoh-Flow runtime.
@@ -31,6 +41,53 @@ pub struct DataFlowInstance {
     pub(crate) state: InstanceState,
     pub(crate) record: DataFlowRecord,
     pub(crate) runners: HashMap<NodeId, Runner>,
+    error_sender: Sender<NodeError>,
+    error_receiver: Receiver<NodeError>,
+    dropped_errors: Arc<AtomicUsize>,
+}
+
+/// The maximum number of [NodeError] a [DataFlowInstance] keeps until they are received. Once that number is reached,
+/// new errors are only logged and counted, see [DataFlowInstance::dropped_errors].
+const NODE_ERRORS_CAPACITY: usize = 1024;
+
+/// A `NodeError` is an error reported by a node of a [DataFlowInstance]: an error returned by its `iteration` or by its
+/// `on_resume` hook, or a panic of its `iteration`.
+///
+/// Note that Zenoh-Flow does not associate deadlines with the nodes: there is no missed deadline to report.
+///
+/// See [DataFlowInstance::errors].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct NodeError {
+    /// The identifier of the node that returned the error.
+    pub node: NodeId,
+    /// When the error was received by the Zenoh-Flow runtime.
+    pub timestamp: Timestamp,
+    /// The error, with its causes.
+    pub error: String,
+}
+
+/// An `ErrorReporter` sends the [errors](NodeError) of the nodes of a [DataFlowInstance] to its receivers, counting
+/// the ones that could not be kept.
+#[derive(Clone)]
+pub(crate) struct ErrorReporter {
+    sender: Sender<NodeError>,
+    hlc: Arc<HLC>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl ErrorReporter {
+    /// Reports the provided `error` of the provided `node`, timestamped with the [HLC] of this reporter.
+    pub(crate) fn report(&self, node: &NodeId, error: String) {
+        let node_error = NodeError {
+            node: node.clone(),
+            timestamp: self.hlc.new_timestamp(),
+            error,
+        };
+
+        if self.sender.try_send(node_error).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
 }
 
 /// The different states of a [DataFlowInstance].
@@ -88,6 +145,51 @@ impl Display for InstanceState {
     }
 }
 
+/// The different states of a node of a [DataFlowInstance], managed by a Zenoh-Flow [runtime].
+///
+/// [runtime]: crate::Runtime
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub enum NodeStatus {
+    /// The node was successfully loaded but was never started.
+    Loaded,
+    /// The `iteration` of the node is being executed in a loop.
+    Running,
+    /// The node was started and then aborted. If it is restarted, its `on_resume` hook will be called first.
+    Aborted,
+}
+
+impl Display for NodeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeStatus::Loaded => write!(f, "Loaded"),
+            NodeStatus::Running => write!(f, "Running"),
+            NodeStatus::Aborted => write!(f, "Aborted"),
+        }
+    }
+}
+
+/// The outcome of aborting a node of a [DataFlowInstance], see [DataFlowInstance::abort_nodes].
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Eq)]
+pub enum AbortOutcome {
+    /// The node was not running: nothing was done.
+    NotRunning,
+    /// The node was aborted and its `on_abort` hook completed.
+    Aborted,
+    /// The node was aborted but its `on_abort` hook did not complete within its
+    /// [graceful_shutdown_timeout](zenoh_flow_nodes::prelude::Node::graceful_shutdown_timeout()): it was cancelled.
+    TimedOut,
+}
+
+impl Display for AbortOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbortOutcome::NotRunning => write!(f, "Not running"),
+            AbortOutcome::Aborted => write!(f, "Aborted"),
+            AbortOutcome::TimedOut => write!(f, "Timed out"),
+        }
+    }
+}
+
 /// The `InstanceStatus` provides information about the data flow instance.
 ///
 /// It details:
@@ -118,13 +220,44 @@ impl Deref for DataFlowInstance {
 impl DataFlowInstance {
     /// Creates a new `DataFlowInstance`, setting its state to [Creating](InstanceState::Creating).
     pub(crate) fn new(record: DataFlowRecord, hlc: &HLC) -> Self {
+        let (error_sender, error_receiver) = flume::bounded(NODE_ERRORS_CAPACITY);
+
         Self {
             state: InstanceState::Creating(hlc.new_timestamp()),
             record,
             runners: HashMap::default(),
+            error_sender,
+            error_receiver,
+            dropped_errors: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Returns an [ErrorReporter] feeding the [errors](DataFlowInstance::errors()) of this `DataFlowInstance`, each
+    /// error being timestamped with the provided [HLC].
+    pub(crate) fn error_reporter(&self, hlc: Arc<HLC>) -> ErrorReporter {
+        ErrorReporter {
+            sender: self.error_sender.clone(),
+            hlc,
+            dropped: self.dropped_errors.clone(),
+        }
+    }
+
+    /// Returns a receiver of the [errors](NodeError) reported by the nodes of this `DataFlowInstance` managed by this
+    /// Zenoh-Flow runtime.
+    ///
+    /// The errors are kept, up to a limit, until they are received. Once that limit is reached, the new errors are only
+    /// logged, see [dropped_errors](DataFlowInstance::dropped_errors()). Note that all the receivers returned by this
+    /// method share the same errors: an error is only given to one of them.
+    pub fn errors(&self) -> Receiver<NodeError> {
+        self.error_receiver.clone()
+    }
+
+    /// Returns the number of [errors](NodeError) that could not be kept because the limit was reached: they were
+    /// logged but will not be received through [errors](DataFlowInstance::errors()).
+    pub fn dropped_errors(&self) -> usize {
+        self.dropped_errors.load(Ordering::Relaxed)
+    }
+
     /// (re-)Starts the `DataFlowInstance`.
     ///
     /// The [hlc](HLC) is required to keep track of when this call was made.
@@ -157,11 +290,124 @@ impl DataFlowInstance {
         self.state = InstanceState::Aborted(hlc.new_timestamp());
     }
 
+    /// (re-)Starts the provided subset of nodes of this `DataFlowInstance`, all or none.
+    ///
+    /// If one of the nodes fails to start, the nodes that were started by this call are aborted and their previous
+    /// [status](NodeStatus) restored before the error is returned. The [state](InstanceState) of the instance is not
+    /// modified: it keeps describing the instance as a whole.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - one of the nodes is not managed by this Zenoh-Flow runtime, in which case no node is started,
+    /// - one of the nodes failed to start, see [start](DataFlowInstance::start()).
+    pub async fn start_nodes(&mut self, nodes: &[NodeId]) -> Result<()> {
+        if let Some(unknown) = nodes.iter().find(|&node| !self.runners.contains_key(node)) {
+            bail!("Node < {} > is not managed by this runtime", unknown);
+        }
+
+        let mut started = Vec::with_capacity(nodes.len());
+        for node_id in nodes {
+            let runner = self.runners.get_mut(node_id).unwrap();
+            let previous_status = runner.status();
+            if previous_status == NodeStatus::Running {
+                continue;
+            }
+
+            if let Err(e) = runner.start().await {
+                for (started_id, previous_status) in started {
+                    if let Some(runner) = self.runners.get_mut(started_id) {
+                        runner.abort_and_restore(previous_status).await;
+                    }
+                }
+                return Err(e.context(format!("Failed to start node < {} >", node_id)));
+            }
+
+            tracing::trace!("Started node < {} >", node_id);
+            started.push((node_id, previous_status));
+        }
+
+        Ok(())
+    }
+
+    /// Aborts the provided subset of nodes of this `DataFlowInstance`, returning how each of them was
+    /// [aborted](AbortOutcome).
+    ///
+    /// The [state](InstanceState) of the instance is not modified: it keeps describing the instance as a whole.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if one of the nodes is not managed by this Zenoh-Flow runtime, in which case no
+    /// node is aborted.
+    pub async fn abort_nodes(&mut self, nodes: &[NodeId]) -> Result<HashMap<NodeId, AbortOutcome>> {
+        if let Some(unknown) = nodes.iter().find(|&node| !self.runners.contains_key(node)) {
+            bail!("Node < {} > is not managed by this runtime", unknown);
+        }
+
+        let mut outcomes = HashMap::with_capacity(nodes.len());
+        for node_id in nodes {
+            if let Some(runner) = self.runners.get_mut(node_id) {
+                let outcome = runner.abort().await;
+                tracing::trace!("Aborted node < {} >: {}", node_id, outcome);
+                outcomes.insert(node_id.clone(), outcome);
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Updates the [Configuration] of the provided node, while it is loaded.
+    ///
+    /// The new configuration is first given to the node, through its [on_configuration_update] hook. If the node
+    /// accepts it, it replaces the previous configuration in the record of this instance.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - the node is not managed by this Zenoh-Flow runtime,
+    /// - the node rejected the new configuration, in which case the previous one is kept.
+    ///
+    /// [on_configuration_update]: zenoh_flow_nodes::prelude::Node::on_configuration_update()
+    pub async fn update_node_configuration(
+        &mut self,
+        node: &NodeId,
+        configuration: Configuration,
+    ) -> Result<()> {
+        let Some(runner) = self.runners.get(node) else {
+            bail!("Node < {} > is not managed by this runtime", node);
+        };
+
+        runner
+            .update_configuration(&configuration)
+            .await
+            .context(format!("Node < {} > rejected its new configuration", node))?;
+
+        if let Some(previous) = self.record.node_configuration_mut(node) {
+            *previous = configuration;
+        }
+
+        Ok(())
+    }
+
     /// Returns the [state](InstanceState) of this `DataFlowInstance`.
     pub fn state(&self) -> &InstanceState {
         &self.state
     }
 
+    /// Returns the [status](NodeStatus) of the node, or `None` if this node is not managed by this Zenoh-Flow runtime.
+    pub fn node_status(&self, node: &NodeId) -> Option<NodeStatus> {
+        self.runners.get(node).map(|runner| runner.status())
+    }
+
+    /// Returns the [description](zenoh_flow_nodes::prelude::Node::description()) and the
+    /// [documentation URL](zenoh_flow_nodes::prelude::Node::documentation_url()) the node provides, or `None` if this
+    /// node is not managed by this Zenoh-Flow runtime.
+    pub fn node_description(&self, node: &NodeId) -> Option<(&'static str, Option<&'static str>)> {
+        self.runners
+            .get(node)
+            .map(|runner| (runner.description(), runner.documentation_url()))
+    }
+
     /// Returns the [status](InstanceStatus) of this `DataFlowInstance`.
     ///
     /// This structure was intended as a way to retrieve and display information about the instance. This is what the
@@ -182,3 +428,331 @@ impl DataFlowInstance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::collections::HashSet;
+    use zenoh_flow_descriptors::FlattenedDataFlowDescriptor;
+    use zenoh_flow_nodes::prelude::{anyhow, Node};
+
+    struct TestNode {
+        fail_on_resume: bool,
+    }
+
+    #[async_trait]
+    impl Node for TestNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(())
+        }
+
+        async fn on_resume(&self) -> Result<()> {
+            if self.fail_on_resume {
+                return Err(anyhow!("on_resume failed"));
+            }
+            Ok(())
+        }
+
+        async fn on_configuration_update(&self, configuration: &Configuration) -> Result<()> {
+            if configuration.get("reject").is_some() {
+                return Err(anyhow!("on_configuration_update failed"));
+            }
+            Ok(())
+        }
+
+        fn description(&self) -> &'static str {
+            "A node that does nothing"
+        }
+    }
+
+    struct FailingNode;
+
+    #[async_trait]
+    impl Node for FailingNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Err(anyhow!("iteration failed"))
+        }
+    }
+
+    struct PanickingNode;
+
+    #[async_trait]
+    impl Node for PanickingNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            panic!("iteration panicked");
+        }
+    }
+
+    struct StuckNode;
+
+    #[async_trait]
+    impl Node for StuckNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(())
+        }
+
+        async fn on_abort(&self) {
+            futures::future::pending::<()>().await;
+        }
+
+        fn graceful_shutdown_timeout(&self) -> std::time::Duration {
+            std::time::Duration::from_millis(50)
+        }
+    }
+
+    /// Replaces the runners of the provided `nodes` with ones wrapping the provided `node`, reporting their errors.
+    fn replace_runners(instance: &mut DataFlowInstance, nodes: &[&NodeId], node: Arc<dyn Node>) {
+        let hlc = Arc::new(HLC::default());
+        for &node_id in nodes {
+            let mut runner = Runner::new(node_id.clone(), node.clone(), None);
+            runner.report_errors_to(instance.error_reporter(hlc.clone()));
+            instance.runners.insert(node_id.clone(), runner);
+        }
+    }
+
+    /// Receives errors from the `instance` until the provided `nodes` all reported one containing `expected`.
+    async fn assert_errors(instance: &DataFlowInstance, nodes: &[&NodeId], expected: &str) {
+        let errors = instance.errors();
+        let mut failed_nodes = HashSet::new();
+        while failed_nodes.len() < nodes.len() {
+            let error =
+                async_std::future::timeout(std::time::Duration::from_secs(1), errors.recv_async())
+                    .await
+                    .expect("No error was reported")
+                    .expect("Channel should not be disconnected");
+            assert!(error.error.contains(expected), "{}", error.error);
+            failed_nodes.insert(error.node);
+        }
+        assert_eq!(
+            nodes
+                .iter()
+                .map(|&node| node.clone())
+                .collect::<HashSet<_>>(),
+            failed_nodes
+        );
+    }
+
+    /// Returns an instance made of a Source and a Sink, where resuming the Sink fails.
+    fn new_instance() -> (DataFlowInstance, NodeId, NodeId) {
+        let flow = r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-1
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-1
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: sink-1
+      input: in-1
+"#;
+        let flattened = serde_yaml::from_str::<FlattenedDataFlowDescriptor>(flow)
+            .expect("Failed to deserialize flow");
+        let record = DataFlowRecord::try_new(&flattened, &RuntimeId::rand())
+            .expect("Failed to create record");
+
+        let source: NodeId = "source-0".into();
+        let sink: NodeId = "sink-1".into();
+
+        let hlc = HLC::default();
+        let mut instance = DataFlowInstance::new(record, &hlc);
+        instance.runners.insert(
+            source.clone(),
+            Runner::new(
+                source.clone(),
+                Arc::new(TestNode {
+                    fail_on_resume: false,
+                }),
+                None,
+            ),
+        );
+        instance.runners.insert(
+            sink.clone(),
+            Runner::new(
+                sink.clone(),
+                Arc::new(TestNode {
+                    fail_on_resume: true,
+                }),
+                None,
+            ),
+        );
+
+        (instance, source, sink)
+    }
+
+    #[async_std::test]
+    async fn test_start_abort_nodes() {
+        let (mut instance, source, sink) = new_instance();
+
+        assert!(instance.start_nodes(&["unknown".into()]).await.is_err());
+        assert_eq!(
+            Some(("A node that does nothing", None)),
+            instance.node_description(&source)
+        );
+        assert!(instance.node_description(&"unknown".into()).is_none());
+
+        instance
+            .start_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to start nodes");
+        assert_eq!(Some(NodeStatus::Running), instance.node_status(&source));
+        assert_eq!(Some(NodeStatus::Running), instance.node_status(&sink));
+
+        let outcomes = instance
+            .abort_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to abort nodes");
+        assert_eq!(
+            HashMap::from([
+                (source.clone(), AbortOutcome::Aborted),
+                (sink.clone(), AbortOutcome::Aborted)
+            ]),
+            outcomes
+        );
+        assert_eq!(Some(NodeStatus::Aborted), instance.node_status(&source));
+
+        let outcomes = instance
+            .abort_nodes(&[source.clone()])
+            .await
+            .expect("Failed to abort source");
+        assert_eq!(
+            HashMap::from([(source.clone(), AbortOutcome::NotRunning)]),
+            outcomes
+        );
+
+        // Resuming the sink fails: the source, started during the same call, must be aborted again.
+        assert!(instance
+            .start_nodes(&[source.clone(), sink.clone()])
+            .await
+            .is_err());
+        assert_eq!(Some(NodeStatus::Aborted), instance.node_status(&source));
+        assert_eq!(Some(NodeStatus::Aborted), instance.node_status(&sink));
+    }
+
+    #[async_std::test]
+    async fn test_abort_nodes_timed_out() {
+        let (mut instance, source, sink) = new_instance();
+        replace_runners(&mut instance, &[&sink], Arc::new(StuckNode));
+
+        instance
+            .start_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to start nodes");
+        let outcomes = instance
+            .abort_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to abort nodes");
+        assert_eq!(
+            HashMap::from([
+                (source.clone(), AbortOutcome::Aborted),
+                (sink.clone(), AbortOutcome::TimedOut)
+            ]),
+            outcomes
+        );
+        assert_eq!(Some(NodeStatus::Aborted), instance.node_status(&sink));
+    }
+
+    #[async_std::test]
+    async fn test_update_node_configuration() {
+        let (mut instance, source, _) = new_instance();
+        instance
+            .start_nodes(&[source.clone()])
+            .await
+            .expect("Failed to start source");
+
+        let configuration =
+            serde_yaml::from_str::<Configuration>("threshold: 42").expect("Failed to parse");
+        instance
+            .update_node_configuration(&source, configuration.clone())
+            .await
+            .expect("Failed to update configuration");
+        assert_eq!(Some(&configuration), instance.node_configuration(&source));
+        assert_eq!(Some(NodeStatus::Running), instance.node_status(&source));
+
+        let rejected =
+            serde_yaml::from_str::<Configuration>("reject: true").expect("Failed to parse");
+        assert!(instance
+            .update_node_configuration(&source, rejected)
+            .await
+            .is_err());
+        assert_eq!(Some(&configuration), instance.node_configuration(&source));
+
+        assert!(instance
+            .update_node_configuration(&"unknown".into(), Configuration::default())
+            .await
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn test_errors() {
+        let (mut instance, source, sink) = new_instance();
+        replace_runners(&mut instance, &[&source, &sink], Arc::new(FailingNode));
+
+        instance
+            .start_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to start nodes");
+        assert_errors(&instance, &[&source, &sink], "iteration failed").await;
+
+        instance
+            .abort_nodes(&[source, sink])
+            .await
+            .expect("Failed to abort nodes");
+        assert_eq!(0, instance.dropped_errors());
+    }
+
+    #[async_std::test]
+    async fn test_errors_panic() {
+        let (mut instance, source, sink) = new_instance();
+        replace_runners(&mut instance, &[&source, &sink], Arc::new(PanickingNode));
+
+        instance
+            .start_nodes(&[source.clone(), sink.clone()])
+            .await
+            .expect("Failed to start nodes");
+        assert_errors(&instance, &[&source, &sink], "iteration panicked").await;
+
+        instance
+            .abort_nodes(&[source, sink])
+            .await
+            .expect("Failed to abort nodes");
+    }
+
+    #[async_std::test]
+    async fn test_errors_on_resume() {
+        let (mut instance, _, sink) = new_instance();
+        let error_reporter = instance.error_reporter(Arc::new(HLC::default()));
+        instance
+            .runners
+            .get_mut(&sink)
+            .unwrap()
+            .report_errors_to(error_reporter);
+
+        instance
+            .start_nodes(&[sink.clone()])
+            .await
+            .expect("Failed to start sink");
+        instance
+            .abort_nodes(&[sink.clone()])
+            .await
+            .expect("Failed to abort sink");
+        assert!(instance.start_nodes(&[sink.clone()]).await.is_err());
+        assert_errors(&instance, &[&sink], "on_resume failed").await;
+    }
+}
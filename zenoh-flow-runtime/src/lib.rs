@@ -23,10 +23,12 @@
 //! [InstanceState] and [InstanceStatus] structures. These structures are leveraged by the `zfctl` command line tool.
 
 mod instance;
-pub use instance::{DataFlowInstance, InstanceState, InstanceStatus};
+pub use instance::{
+    AbortOutcome, DataFlowInstance, InstanceState, InstanceStatus, NodeError, NodeStatus,
+};
 
 mod loader;
-pub use loader::{Extension, Extensions};
+pub use loader::{Extension, Extensions, VersionMismatch};
 
 #[cfg(feature = "shared-memory")]
 mod shared_memory;
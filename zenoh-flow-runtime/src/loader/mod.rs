@@ -24,9 +24,10 @@ use std::{
     str::FromStr,
     sync::Arc,
 };
+use thiserror::Error;
 use url::Url;
 use zenoh_flow_commons::Result;
-use zenoh_flow_nodes::{NodeDeclaration, CORE_VERSION, RUSTC_VERSION};
+use zenoh_flow_nodes::{NodeDeclaration, OperatorFn, CORE_VERSION, RUSTC_VERSION};
 
 /// NodeSymbol groups the symbol we must find in the shared library we load.
 pub(crate) enum NodeSymbol {
@@ -52,6 +53,18 @@ impl NodeSymbol {
     }
 }
 
+/// This enumeration defines the version mismatches that can prevent a Zenoh-Flow runtime from loading a node.
+///
+/// A node that was not compiled with the same version of the Rust compiler or of Zenoh-Flow as the Zenoh-Flow runtime
+/// is not ABI compatible and thus cannot be loaded.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VersionMismatch {
+    #[error("expected rustc {expected}, got {got}")]
+    RustCompiler { expected: String, got: String },
+    #[error("expected zenoh-flow {expected}, got {got}")]
+    ZenohFlow { expected: String, got: String },
+}
+
 /// Validates that the library exposes the correct symbols for the provided constructor.
 ///
 /// # Errors
@@ -69,28 +82,20 @@ pub(crate) fn validate_library<N>(library: &Library, node_symbol: &NodeSymbol) -
     };
 
     // version checks to prevent accidental ABI incompatibilities
-    if decl.rustc_version != RUSTC_VERSION || decl.core_version != CORE_VERSION {
-        if decl.rustc_version != RUSTC_VERSION {
-            bail!(
-                r#"
-It appears that the node was not compiled with the same version of the Rust compiler than Zenoh-Flow:
-- (expected, Zenoh-Flow): {}
-- (found, Node): {}
-"#,
-                RUSTC_VERSION,
-                decl.rustc_version,
-            )
+    if decl.rustc_version != RUSTC_VERSION {
+        return Err(VersionMismatch::RustCompiler {
+            expected: RUSTC_VERSION.to_string(),
+            got: decl.rustc_version.to_string(),
         }
+        .into());
+    }
 
-        bail!(
-            r#"
-It appears that the node was not compiled with the same version of Zenoh-Flow than that of this Zenoh-Flow runtime:
-- (expected, this Zenoh-Flow runtime): {}
-- (found, Node): {}
-"#,
-            CORE_VERSION,
-            decl.core_version,
-        )
+    if decl.core_version != CORE_VERSION {
+        return Err(VersionMismatch::ZenohFlow {
+            expected: CORE_VERSION.to_string(),
+            got: decl.core_version.to_string(),
+        }
+        .into());
     }
 
     Ok(())
@@ -143,6 +148,7 @@ pub(crate) fn try_get_constructor<N>(
 pub(crate) struct Loader {
     pub(crate) extensions: Extensions,
     pub(crate) libraries: HashMap<Url, Arc<Library>>,
+    pub(crate) inline_operators: HashMap<Arc<str>, OperatorFn>,
 }
 
 impl Deref for Loader {
@@ -183,6 +189,70 @@ impl Loader {
             .try_add_extension(file_extension, source, operator, sink)
     }
 
+    /// Attempts to replace the extension associated with the provided file extension, returning the previous one.
+    ///
+    /// The shared libraries that were loaded through the previous extension are removed from the cache of this
+    /// `Loader` such that the nodes created afterwards will use the new libraries.
+    ///
+    /// Note that the nodes already created keep using the libraries they were created with: these libraries will only
+    /// be unloaded once all the data flow instances that use them are deleted.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if any of the new libraries did not pass the checks described in
+    /// [try_add_extension](Loader::try_add_extension()). In that case, the previous extension is kept.
+    pub(crate) fn try_reload_extension(
+        &mut self,
+        file_extension: impl Into<String>,
+        source: impl Into<PathBuf>,
+        operator: impl Into<PathBuf>,
+        sink: impl Into<PathBuf>,
+    ) -> Result<Option<Extension>> {
+        let file_extension = file_extension.into();
+        let previous_extension =
+            self.try_add_extension(file_extension.as_str(), source, operator, sink)?;
+
+        self.libraries.retain(|url, _| {
+            PathBuf::from(url.path())
+                .extension()
+                .and_then(|ext| ext.to_str())
+                != Some(file_extension.as_str())
+        });
+
+        Ok(previous_extension)
+    }
+
+    /// Registers the constructor of an Operator under the provided `name`, returning the constructor previously
+    /// registered under that name, if any.
+    ///
+    /// An Operator whose library is `inline://<name>` will be created by calling this constructor instead of loading
+    /// a shared library.
+    pub(crate) fn add_inline_operator(
+        &mut self,
+        name: impl Into<Arc<str>>,
+        constructor: OperatorFn,
+    ) -> Option<OperatorFn> {
+        self.inline_operators.insert(name.into(), constructor)
+    }
+
+    /// Returns the constructor of the inline Operator referenced by the provided [Url].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the scheme of the Url is not "inline" or if no constructor was registered
+    /// under the name it references.
+    pub(crate) fn try_get_inline_operator(&self, url: &Url) -> Result<OperatorFn> {
+        if url.scheme() != "inline" {
+            bail!("Expected an < inline:// > Url, found:\n{}", url);
+        }
+
+        let name = url.host_str().unwrap_or_default();
+        self.inline_operators
+            .get(name)
+            .copied()
+            .ok_or_else(|| anyhow!("No inline Operator registered under the name < {} >", name))
+    }
+
     /// This method will free the shared libraries that are no longer being used.
     ///
     /// Every time a data flow is created, each node will receive an `Arc<Library>` of the shared library it
@@ -293,3 +363,31 @@ impl Loader {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_operator() {
+        let constructor: OperatorFn =
+            |_, _, _, _| Box::pin(async { Err(anyhow!("Inline operator")) });
+
+        let mut loader = Loader::default();
+        assert!(loader
+            .add_inline_operator("my-operator", constructor)
+            .is_none());
+        assert!(loader
+            .add_inline_operator("my-operator", constructor)
+            .is_some());
+
+        let url = Url::parse("inline://my-operator").unwrap();
+        assert!(loader.try_get_inline_operator(&url).is_ok());
+
+        let url = Url::parse("inline://unknown").unwrap();
+        assert!(loader.try_get_inline_operator(&url).is_err());
+
+        let url = Url::parse("file:///home/zenoh-flow/libmy-operator.so").unwrap();
+        assert!(loader.try_get_inline_operator(&url).is_err());
+    }
+}
@@ -112,7 +112,13 @@ Caused by:
             .collect();
 
         #[cfg(feature = "shared-memory")]
-        let shm = SharedMemory::new(&id, session.clone(), shm_configuration);
+        // NOTE: The built-in Zenoh Sink has no link settings, it keeps blocking when the network is congested.
+        let shm = SharedMemory::new(
+            &id,
+            session.clone(),
+            shm_configuration,
+            CongestionControl::Block,
+        );
 
         Ok(Self {
             id,
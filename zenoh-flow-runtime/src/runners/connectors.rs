@@ -25,10 +25,29 @@ use crate::shared_memory::SharedMemory;
 #[cfg(feature = "shared-memory")]
 use zenoh_flow_commons::SharedMemoryConfiguration;
 
+/// Converts the congestion control of a link into its Zenoh counterpart.
+fn congestion_control(
+    congestion_control: zenoh_flow_descriptors::CongestionControl,
+) -> CongestionControl {
+    match congestion_control {
+        zenoh_flow_descriptors::CongestionControl::Drop => CongestionControl::Drop,
+        zenoh_flow_descriptors::CongestionControl::Block => CongestionControl::Block,
+    }
+}
+
+/// Converts the reliability of a link into its Zenoh counterpart.
+fn reliability(reliability: zenoh_flow_descriptors::Reliability) -> Reliability {
+    match reliability {
+        zenoh_flow_descriptors::Reliability::BestEffort => Reliability::BestEffort,
+        zenoh_flow_descriptors::Reliability::Reliable => Reliability::Reliable,
+    }
+}
+
 pub(crate) struct ZenohConnectorSender {
     id: NodeId,
     input: InputRaw,
     key_expr: OwnedKeyExpr,
+    congestion_control: CongestionControl,
     session: Arc<Session>,
     state: Arc<Mutex<State>>,
 }
@@ -53,14 +72,22 @@ impl ZenohConnectorSender {
             .ok_or_else(|| anyhow!(""))?
             .raw();
 
+        let congestion_control = congestion_control(record.congestion_control());
+
         Ok(Self {
             input,
             key_expr: record.resource().clone(),
+            congestion_control,
             state: Arc::new(Mutex::new(State {
                 payload_buffer: Vec::new(),
                 message_buffer: Vec::new(),
                 #[cfg(feature = "shared-memory")]
-                shm: SharedMemory::new(&record.id, session.clone(), shm_config),
+                shm: SharedMemory::new(
+                    &record.id(),
+                    session.clone(),
+                    shm_config,
+                    congestion_control,
+                ),
             })),
             id: record.id(),
             session,
@@ -109,6 +136,7 @@ Caused by:
 
                         self.session
                             .put(&self.key_expr, message_buffer)
+                            .congestion_control(self.congestion_control)
                             .res()
                             .await
                             .map_err(|e| {
@@ -133,6 +161,7 @@ Caused by:
 
                     self.session
                         .put(&self.key_expr, message_buffer)
+                        .congestion_control(self.congestion_control)
                         .res()
                         .await
                         .map_err(|e| {
@@ -174,6 +203,7 @@ pub(crate) struct ZenohConnectorReceiver {
     pub(crate) key_expr: OwnedKeyExpr,
     pub(crate) output_raw: OutputRaw,
     pub(crate) subscriber: FlumeSubscriber<'static>,
+    pub(crate) reliability: Reliability,
 }
 
 impl ZenohConnectorReceiver {
@@ -189,8 +219,10 @@ impl ZenohConnectorReceiver {
             // TODO@J-Loudet
             .map_err(|e| anyhow!("{:?}", e))?;
 
+        let reliability = reliability(record.reliability());
         let subscriber = session
             .declare_subscriber(ke)
+            .reliability(reliability)
             .res()
             .await
             // TODO@J-Loudet
@@ -207,6 +239,7 @@ impl ZenohConnectorReceiver {
             key_expr: record.resource().clone(),
             output_raw,
             subscriber,
+            reliability,
         })
     }
 }
@@ -224,11 +257,12 @@ impl Node for ZenohConnectorReceiver {
             Err(e) => {
                 tracing::error!(
                     r#"
-[connector receiver (zenoh): {}][key expr: {}] Zenoh subscriber returned the following error:
+[connector receiver (zenoh): {}][key expr: {}][reliability: {:?}] Zenoh subscriber returned the following error:
 {:?}
 "#,
                     self.id,
                     self.key_expr,
+                    self.reliability,
                     e
                 );
                 bail!("{:?}", e)
@@ -236,3 +270,110 @@ impl Node for ZenohConnectorReceiver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uhlc::HLC;
+    use zenoh_flow_commons::{RuntimeId, Vars};
+    use zenoh_flow_descriptors::{DataFlowDescriptor, FlattenedDataFlowDescriptor};
+    use zenoh_flow_records::DataFlowRecord;
+
+    #[test]
+    fn test_zenoh_defaults() {
+        assert_eq!(
+            CongestionControl::default(),
+            congestion_control(zenoh_flow_descriptors::CongestionControl::default())
+        );
+        assert_eq!(
+            Reliability::default(),
+            reliability(zenoh_flow_descriptors::Reliability::default())
+        );
+    }
+
+    #[async_std::test]
+    async fn test_link_settings() {
+        let runtime_thing = RuntimeId::rand();
+        let runtime_edge = RuntimeId::rand();
+
+        let desc = format!(
+            r#"
+name: test-flow
+
+sources:
+  - id: source-0
+    library: file:///home/zenoh-flow/libsource.so
+    outputs:
+      - out-0
+
+sinks:
+  - id: sink-1
+    library: file:///home/zenoh-flow/libsink.so
+    inputs:
+      - in-1
+
+links:
+  - from:
+      node: source-0
+      output: out-0
+    to:
+      node: sink-1
+      input: in-1
+    reliability: reliable
+    congestion_control: block
+
+mapping:
+  {}:
+    - source-0
+  {}:
+    - sink-1
+"#,
+            runtime_thing, runtime_edge
+        );
+
+        let flat_desc = FlattenedDataFlowDescriptor::try_flatten(
+            serde_yaml::from_str::<DataFlowDescriptor>(&desc).unwrap(),
+            Vars::default(),
+        )
+        .unwrap();
+        let record = DataFlowRecord::try_new(&flat_desc, &runtime_thing).unwrap();
+        let sender_record = record.senders().values().next().unwrap().clone();
+        let receiver_record = record.receivers().values().next().unwrap().clone();
+
+        let session = zenoh::open(zenoh::config::peer())
+            .res()
+            .await
+            .expect("Failed to open Zenoh session")
+            .into_arc();
+
+        let (tx, rx) = flume::unbounded();
+        let mut inputs = Inputs::default();
+        inputs.insert(sender_record.resource().to_string().into(), rx);
+        #[cfg(feature = "shared-memory")]
+        let shm_config = SharedMemoryConfiguration {
+            size: 1024,
+            backoff: 0,
+        };
+        let sender = ZenohConnectorSender::try_new(
+            session.clone(),
+            #[cfg(feature = "shared-memory")]
+            &shm_config,
+            sender_record,
+            inputs,
+        )
+        .expect("Failed to create sender");
+        assert_eq!(CongestionControl::Block, sender.congestion_control);
+        #[cfg(feature = "shared-memory")]
+        assert_eq!(
+            CongestionControl::Block,
+            sender.state.lock().await.shm.congestion_control
+        );
+
+        let mut outputs = Outputs::new(Arc::new(HLC::default()));
+        outputs.insert(receiver_record.resource().to_string().into(), tx);
+        let receiver = ZenohConnectorReceiver::try_new(session, receiver_record, outputs)
+            .await
+            .expect("Failed to create receiver");
+        assert_eq!(Reliability::Reliable, receiver.reliability);
+    }
+}
@@ -19,13 +19,19 @@ pub(crate) mod connectors;
 
 use anyhow::Context;
 use async_std::task::JoinHandle;
+use futures::FutureExt;
 use libloading::Library;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::Instrument;
-use zenoh_flow_commons::{NodeId, Result};
+use zenoh_flow_commons::{Configuration, NodeId, Result};
 use zenoh_flow_nodes::prelude::Node;
 
+use crate::instance::ErrorReporter;
+use crate::{AbortOutcome, NodeStatus};
+
 enum State {
     Uninitialized,
     Initialized,
@@ -45,6 +51,9 @@ pub(crate) struct Runner {
     // The `Option` exists because only user-implemented nodes have a `Library`. For example, built-in Zenoh Source /
     // Sink and the connectors have no `Library`.
     _library: Option<Arc<Library>>,
+    // Where the errors of the Node (returned by `iteration` or `on_resume`, or a panic of `iteration`) are reported, in
+    // addition to being logged.
+    error_reporter: Option<ErrorReporter>,
 }
 
 impl Runner {
@@ -62,32 +71,81 @@ impl Runner {
             state: State::Uninitialized,
             handle: None,
             _library: library,
+            error_reporter: None,
         }
     }
 
+    /// Reports the errors of the [Node] this Runner wraps to the provided [ErrorReporter]: the errors returned by its
+    /// `iteration` and `on_resume` methods and the panics of its `iteration`.
+    ///
+    /// This only applies to the next call to [start](Runner::start()).
+    pub(crate) fn report_errors_to(&mut self, error_reporter: ErrorReporter) {
+        self.error_reporter = Some(error_reporter);
+    }
+
     /// Returns `true` if the Runner is running, i.e. if the `iteration` of the [Node] it wraps is being polled in a
     /// loop.
     pub(crate) fn is_running(&self) -> bool {
         self.handle.is_some()
     }
 
+    /// Returns the [status](NodeStatus) of the [Node] this Runner wraps.
+    pub(crate) fn status(&self) -> NodeStatus {
+        match (&self.state, self.is_running()) {
+            (_, true) => NodeStatus::Running,
+            (State::Uninitialized, false) => NodeStatus::Loaded,
+            (State::Initialized, false) => NodeStatus::Aborted,
+        }
+    }
+
+    /// Returns the [description](Node::description()) of the [Node] this Runner wraps.
+    pub(crate) fn description(&self) -> &'static str {
+        self.node.description()
+    }
+
+    /// Forwards the new [Configuration] to the [Node] this Runner wraps, see [Node::on_configuration_update].
+    pub(crate) async fn update_configuration(&self, configuration: &Configuration) -> Result<()> {
+        self.node.on_configuration_update(configuration).await
+    }
+
+    /// Returns the [documentation URL](Node::documentation_url()) of the [Node] this Runner wraps.
+    pub(crate) fn documentation_url(&self) -> Option<&'static str> {
+        self.node.documentation_url()
+    }
+
     /// Starts the runner: run the `iteration` method of the [Node] it wraps in a loop.
     ///
+    /// An error returned by the `iteration` is logged and reported, the loop then continues. The same goes for a panic:
+    /// it is caught, logged and reported, as the node might be able to process its next inputs.
+    ///
     /// This method is also idempotent: if the runner is already running, nothing will happen.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the runner is restarted and the `on_resume` method of the [Node] failed. The
+    /// error is also reported.
     pub(crate) async fn start(&mut self) -> Result<()> {
         if self.is_running() {
             return Ok(());
         }
 
         if matches!(self.state, State::Initialized) {
-            self.node
+            if let Err(e) = self
+                .node
                 .on_resume()
                 .await
-                .with_context(|| format!("{}: call to `on_resume` failed", self.id))?;
+                .with_context(|| format!("{}: call to `on_resume` failed", self.id))
+            {
+                if let Some(error_reporter) = &self.error_reporter {
+                    error_reporter.report(&self.id, format!("{:?}", e));
+                }
+                return Err(e);
+            }
         }
 
         let id = self.id.clone();
         let node = self.node.clone();
+        let error_reporter = self.error_reporter.clone();
         let iteration_span = tracing::trace_span!("iteration", node = %id);
 
         self.handle = Some(async_std::task::spawn(
@@ -96,10 +154,24 @@ impl Runner {
                 let mut iteration;
                 loop {
                     instant = Instant::now();
-                    iteration = node.iteration().await;
+                    // NOTE: The node is shared with the Runner, which only calls its hooks once this task is cancelled.
+                    // A panic thus cannot be observed by another task while the node is in an inconsistent state.
+                    iteration = AssertUnwindSafe(node.iteration()).catch_unwind().await;
                     tracing::trace!("duration: {}µs", instant.elapsed().as_micros());
-                    if let Err(e) = iteration {
-                        tracing::error!("{:?}", e);
+                    let error = match iteration {
+                        Ok(Ok(())) => None,
+                        Ok(Err(e)) => Some(format!("{:?}", e)),
+                        Err(panic) => Some(format!(
+                            "call to `iteration` panicked: {}",
+                            panic_message(panic.as_ref())
+                        )),
+                    };
+
+                    if let Some(error) = error {
+                        tracing::error!("{}", error);
+                        if let Some(error_reporter) = &error_reporter {
+                            error_reporter.report(&id, error);
+                        }
                     }
 
                     async_std::task::yield_now().await;
@@ -112,6 +184,18 @@ impl Runner {
         Ok(())
     }
 
+    /// Aborts the runner and restores the provided [status](NodeStatus), the one it had before it was last started.
+    ///
+    /// This method is intended to roll back a call to [start](Runner::start()) that is part of an operation that failed
+    /// as a whole. If the previous status is [Loaded](NodeStatus::Loaded), the node is considered as never having been
+    /// started: its `on_resume` hook will not be called when it is started again.
+    pub(crate) async fn abort_and_restore(&mut self, previous_status: NodeStatus) {
+        self.abort().await;
+        if previous_status == NodeStatus::Loaded {
+            self.state = State::Uninitialized;
+        }
+    }
+
     /// Aborts the runner: stop the execution of its `iteration` method at its nearest `await` point.
     ///
     /// This method is idempotent: if the runner is not running, nothing will happen.
@@ -124,10 +208,180 @@ impl Runner {
     /// What this also means is that there is a possibility to leave the node in an **inconsistent state**. For
     /// instance, modified values that are not saved between several `.await` points would be lost if the node is
     /// aborted.
-    pub(crate) async fn abort(&mut self) {
-        if let Some(handle) = self.handle.take() {
-            handle.cancel().await;
-            self.node.on_abort().await;
+    ///
+    /// The `on_abort` hook of the node is then called and cancelled if it does not complete within the
+    /// [graceful_shutdown_timeout](Node::graceful_shutdown_timeout()) of the node, which the returned
+    /// [AbortOutcome] reflects.
+    pub(crate) async fn abort(&mut self) -> AbortOutcome {
+        let Some(handle) = self.handle.take() else {
+            return AbortOutcome::NotRunning;
+        };
+
+        handle.cancel().await;
+
+        let timeout = self.node.graceful_shutdown_timeout();
+        if async_std::future::timeout(timeout, self.node.on_abort())
+            .await
+            .is_err()
+        {
+            tracing::warn!(
+                "{}: call to `on_abort` did not complete within {:?} and was cancelled",
+                self.id,
+                timeout
+            );
+            return AbortOutcome::TimedOut;
+        }
+
+        AbortOutcome::Aborted
+    }
+}
+
+/// Returns the message of a panic, if it is a string (which is the case of the ones raised by `panic!`).
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non-string panic payload>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::time::Duration;
+    use zenoh_flow_nodes::prelude::{Input, Inputs, LinkMessage, Output, Outputs};
+
+    struct TestNode;
+
+    #[async_trait]
+    impl Node for TestNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(())
+        }
+    }
+
+    struct StuckNode;
+
+    #[async_trait]
+    impl Node for StuckNode {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(())
+        }
+
+        async fn on_abort(&self) {
+            futures::future::pending::<()>().await;
+        }
+
+        fn graceful_shutdown_timeout(&self) -> Duration {
+            Duration::from_millis(50)
+        }
+    }
+
+    struct FilterNode {
+        input: Input<u64>,
+        output: Output<u64>,
+    }
+
+    #[async_trait]
+    impl Node for FilterNode {
+        async fn iteration(&self) -> Result<()> {
+            let (data, _) = self.input.recv().await?;
+            if *data % 2 == 0 {
+                self.output.send(*data, None).await?;
+            }
+
+            Ok(())
+        }
+    }
+
+    #[async_std::test]
+    async fn test_filter_does_not_stall() {
+        let hlc = Arc::new(uhlc::HLC::default());
+        let (tx_in, rx_in) = flume::unbounded::<LinkMessage>();
+        let (tx_out, rx_out) = flume::unbounded::<LinkMessage>();
+
+        let mut inputs = Inputs::default();
+        inputs.insert("in".into(), rx_in);
+        let mut outputs = Outputs::new(hlc.clone());
+        outputs.insert("out".into(), tx_out);
+
+        let node = FilterNode {
+            input: inputs
+                .take("in")
+                .unwrap()
+                .typed(|bytes| bincode::deserialize(bytes).map_err(|e| anyhow::anyhow!(e))),
+            output: outputs.take("out").unwrap().typed(|buffer, data| {
+                bincode::serialize_into(buffer, data).map_err(|e| anyhow::anyhow!(e))
+            }),
+        };
+
+        let mut runner = Runner::new("filter".into(), Arc::new(node), None);
+        runner.start().await.expect("Failed to start runner");
+
+        for i in 1u64..=6 {
+            tx_in
+                .send_async(LinkMessage::new_serialized(
+                    bincode::serialize(&i).unwrap(),
+                    hlc.new_timestamp(),
+                ))
+                .await
+                .expect("Failed to send message");
         }
+
+        let mut inputs = Inputs::default();
+        inputs.insert("downstream".into(), rx_out);
+        let downstream = inputs
+            .take("downstream")
+            .unwrap()
+            .typed(|bytes| bincode::deserialize::<u64>(bytes).map_err(|e| anyhow::anyhow!(e)));
+
+        for expected in [2u64, 4, 6] {
+            let (data, _) = async_std::future::timeout(Duration::from_secs(1), downstream.recv())
+                .await
+                .expect("The filter stalled")
+                .expect("Failed to receive data");
+            assert_eq!(expected, *data);
+        }
+
+        runner.abort().await;
+        assert!(tx_in.is_empty());
+        assert!(downstream
+            .try_recv()
+            .expect("Channel should not be disconnected")
+            .is_none());
+    }
+
+    #[async_std::test]
+    async fn test_status() {
+        let mut runner = Runner::new("test-node".into(), Arc::new(TestNode), None);
+        assert_eq!(NodeStatus::Loaded, runner.status());
+
+        runner.start().await.expect("Failed to start runner");
+        assert_eq!(NodeStatus::Running, runner.status());
+
+        runner.abort().await;
+        assert_eq!(NodeStatus::Aborted, runner.status());
+
+        runner.start().await.expect("Failed to restart runner");
+        assert_eq!(NodeStatus::Running, runner.status());
+
+        runner.abort().await;
+        assert_eq!(NodeStatus::Aborted, runner.status());
+    }
+
+    #[async_std::test]
+    async fn test_graceful_shutdown_timeout() {
+        let mut runner = Runner::new("stuck-node".into(), Arc::new(StuckNode), None);
+        runner.start().await.expect("Failed to start runner");
+
+        let outcome = async_std::future::timeout(Duration::from_secs(1), runner.abort())
+            .await
+            .expect("The call to `on_abort` was not cancelled");
+        assert_eq!(AbortOutcome::TimedOut, outcome);
+        assert_eq!(NodeStatus::Aborted, runner.status());
+        assert_eq!(AbortOutcome::NotRunning, runner.abort().await);
     }
 }
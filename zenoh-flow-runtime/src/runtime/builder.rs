@@ -23,6 +23,7 @@ use zenoh::prelude::r#async::*;
 #[cfg(feature = "shared-memory")]
 use zenoh_flow_commons::SharedMemoryConfiguration;
 use zenoh_flow_commons::{Result, RuntimeId};
+use zenoh_flow_nodes::OperatorFn;
 
 /// Builder structure to help create a [Runtime].
 ///
@@ -216,6 +217,56 @@ impl RuntimeBuilder {
         Ok(self)
     }
 
+    /// Registers an Operator, created by the provided `constructor`, under the given `name`.
+    ///
+    /// Such an Operator is referenced in a descriptor with the library `inline://<name>`: the Runtime will call the
+    /// constructor instead of loading a shared library. This is convenient to embed small pieces of logic in an
+    /// application without having to compile them separately.
+    ///
+    /// If a constructor was already registered under the same name, it is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use zenoh_flow_runtime::Runtime;
+    /// use zenoh_flow_nodes::prelude::*;
+    ///
+    /// struct MyOperator;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl Operator for MyOperator {
+    ///     async fn new(
+    ///         _context: Context,
+    ///         _configuration: Configuration,
+    ///         _inputs: Inputs,
+    ///         _outputs: Outputs,
+    ///     ) -> Result<Self> {
+    ///         Ok(Self)
+    ///     }
+    /// }
+    ///
+    /// #[async_trait::async_trait]
+    /// impl Node for MyOperator {
+    ///     async fn iteration(&self) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let builder = Runtime::builder("demo").add_inline_operator("my-operator", |ctx, cfg, i, o| {
+    ///     Box::pin(async move {
+    ///         Ok(std::sync::Arc::new(MyOperator::new(ctx, cfg, i, o).await?) as std::sync::Arc<dyn Node>)
+    ///     })
+    /// });
+    /// ```
+    pub fn add_inline_operator(
+        mut self,
+        name: impl Into<Arc<str>>,
+        constructor: OperatorFn,
+    ) -> Self {
+        self.loader.add_inline_operator(name, constructor);
+        self
+    }
+
     /// Attempts to build the [Runtime].
     ///
     /// # Errors
@@ -174,6 +174,9 @@ impl Runtime {
             return Err(e);
         }
 
+        for runner in runners.values_mut() {
+            runner.report_errors_to(instance_guard.error_reporter(self.hlc.clone()));
+        }
         instance_guard.runners = runners;
         instance_guard.state = InstanceState::Loaded(self.hlc.new_timestamp());
 
@@ -284,11 +287,23 @@ The channels for the Inputs and Outputs of Operator < {} > were not created.
                 &operator_id
             ))?;
 
-            let (constructor, library) = self
-                .try_load_constructor::<OperatorFn>(&operator.library, &NodeSymbol::Operator)
-                .await?;
+            let (constructor, library) = if operator.library.scheme() == "inline" {
+                let constructor = self
+                    .loader
+                    .lock()
+                    .await
+                    .try_get_inline_operator(&operator.library)
+                    .context(format!("Failed to load Operator < {} >", operator.id))?;
+                (constructor, None)
+            } else {
+                let (constructor, library) = self
+                    .try_load_constructor::<OperatorFn>(&operator.library, &NodeSymbol::Operator)
+                    .await
+                    .context(format!("Failed to load Operator < {} >", operator.id))?;
+                (constructor, Some(library))
+            };
             let operator_node = (constructor)(
-                context.clone(),
+                context.clone().with_node_id(operator.id.clone()),
                 operator.configuration.clone(),
                 inputs,
                 outputs,
@@ -296,7 +311,7 @@ The channels for the Inputs and Outputs of Operator < {} > were not created.
             .await?;
             runners.insert(
                 operator_id.clone(),
-                Runner::new(operator_id.clone(), operator_node, Some(library)),
+                Runner::new(operator_id.clone(), operator_node, library),
             );
         }
 
@@ -344,10 +359,14 @@ The channels for the Outputs of Source < {} > were not created.
                 SourceVariant::Library(uri) => {
                     let (constructor, library) = self
                         .try_load_constructor::<SourceFn>(uri, &NodeSymbol::Source)
-                        .await?;
-                    let source_node =
-                        (constructor)(context.clone(), source.configuration.clone(), outputs)
-                            .await?;
+                        .await
+                        .context(format!("Failed to load Source < {} >", source.id))?;
+                    let source_node = (constructor)(
+                        context.clone().with_node_id(source.id.clone()),
+                        source.configuration.clone(),
+                        outputs,
+                    )
+                    .await?;
 
                     Runner::new(source.id.clone(), source_node, Some(library))
                 }
@@ -416,9 +435,14 @@ The channels for the Inputs of Sink < {} > were not created.
                 SinkVariant::Library(uri) => {
                     let (constructor, library) = self
                         .try_load_constructor::<SinkFn>(uri, &NodeSymbol::Sink)
-                        .await?;
-                    let sink_node =
-                        (constructor)(context.clone(), sink.configuration.clone(), inputs).await?;
+                        .await
+                        .context(format!("Failed to load Sink < {} >", sink.id))?;
+                    let sink_node = (constructor)(
+                        context.clone().with_node_id(sink.id.clone()),
+                        sink.configuration.clone(),
+                        inputs,
+                    )
+                    .await?;
 
                     Runner::new(sink.id.clone(), sink_node, Some(library))
                 }
@@ -18,26 +18,28 @@ pub use builder::RuntimeBuilder;
 mod load;
 
 use crate::{
-    instance::{DataFlowInstance, InstanceStatus},
-    loader::Loader,
+    instance::{AbortOutcome, DataFlowInstance, InstanceStatus, NodeError, NodeStatus},
+    loader::{Extension, Loader},
     InstanceState,
 };
 
 use std::{
     collections::HashMap,
     fmt::{Debug, Display},
+    path::PathBuf,
     sync::Arc,
 };
 
 use anyhow::{anyhow, bail};
 use async_std::sync::{Mutex, RwLock};
+use flume::Receiver;
 use thiserror::Error;
 use uhlc::HLC;
 #[cfg(feature = "zenoh")]
 use zenoh::Session;
 #[cfg(feature = "shared-memory")]
 use zenoh_flow_commons::SharedMemoryConfiguration;
-use zenoh_flow_commons::{InstanceId, Result, RuntimeId};
+use zenoh_flow_commons::{Configuration, InstanceId, NodeId, Result, RuntimeId};
 use zenoh_flow_records::DataFlowRecord;
 
 /// A Zenoh-Flow runtime manages a subset of the nodes of [DataFlowInstance]\(s\).
@@ -117,6 +119,40 @@ impl Runtime {
         self.hlc.clone()
     }
 
+    /// Attempts to replace the [Extension] associated with the provided `file_extension` with the provided libraries,
+    /// returning the previous one (if any).
+    ///
+    /// This allows deploying a new version of an extension without restarting the Zenoh-Flow runtime. Note that only
+    /// the nodes of the data flows instantiated *after* this call will use the new libraries: the nodes of the existing
+    /// data flow instances keep using the previous ones until these instances are deleted.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if any of the libraries:
+    /// - does not expose the correct symbol,
+    /// - was not compiled with the same Rust version,
+    /// - was not using the same Zenoh-Flow version as this Zenoh-Flow runtime.
+    ///
+    /// In that case, the previous extension is kept.
+    pub async fn try_reload_extension(
+        &self,
+        file_extension: impl Into<String>,
+        source: impl Into<PathBuf>,
+        operator: impl Into<PathBuf>,
+        sink: impl Into<PathBuf>,
+    ) -> Result<Option<Extension>> {
+        let file_extension = file_extension.into();
+        let previous_extension = self.loader.lock().await.try_reload_extension(
+            file_extension.as_str(),
+            source,
+            operator,
+            sink,
+        )?;
+        tracing::info!("Reloaded extension < {} >", file_extension);
+
+        Ok(previous_extension)
+    }
+
     /// Returns the [InstanceState] of the [DataFlowInstance]\(s\) managed by this Zenoh-Flow runtime.
     pub async fn instances_state(&self) -> HashMap<InstanceId, (Arc<str>, InstanceState)> {
         let flows = self.flows.read().await;
@@ -172,6 +208,46 @@ impl Runtime {
         None
     }
 
+    /// Returns a receiver of the [errors](NodeError) returned by the nodes of the provided data flow instance, or
+    /// [None] if this runtime does not manage this instance.
+    ///
+    /// See [DataFlowInstance::errors].
+    pub async fn get_instance_errors(&self, id: &InstanceId) -> Option<Receiver<NodeError>> {
+        if let Some(instance) = self.flows.read().await.get(id) {
+            return Some(instance.read().await.errors());
+        }
+
+        None
+    }
+
+    /// Returns the [status](NodeStatus) of the provided node of the provided data flow instance, or [None] if this
+    /// runtime does not manage this instance or this node.
+    ///
+    /// See [DataFlowInstance::node_status].
+    pub async fn get_node_status(&self, id: &InstanceId, node: &NodeId) -> Option<NodeStatus> {
+        if let Some(instance) = self.flows.read().await.get(id) {
+            return instance.read().await.node_status(node);
+        }
+
+        None
+    }
+
+    /// Returns the description and the documentation URL the provided node of the provided data flow instance exposes,
+    /// or [None] if this runtime does not manage this instance or this node.
+    ///
+    /// See [DataFlowInstance::node_description].
+    pub async fn get_node_description(
+        &self,
+        id: &InstanceId,
+        node: &NodeId,
+    ) -> Option<(&'static str, Option<&'static str>)> {
+        if let Some(instance) = self.flows.read().await.get(id) {
+            return instance.read().await.node_description(node);
+        }
+
+        None
+    }
+
     /// Tries to retrieve the [DataFlowInstance] matching the provided [id](InstanceId) from the Zenoh-Flow runtime.
     ///
     /// # Errors
@@ -246,6 +322,154 @@ impl Runtime {
         Ok(())
     }
 
+    /// Attempts to (re-)start the provided `nodes` of the [DataFlowInstance] identified by the provided `id`, all or
+    /// none.
+    ///
+    /// The nodes that are already running are left untouched. The [state](InstanceState) of the instance is not
+    /// modified: it keeps describing the instance as a whole.
+    ///
+    /// # Errors
+    ///
+    /// This method can fail for the following reasons:
+    /// - no data flow with the provided id was found,
+    /// - the data flow is in a failed state,
+    /// - one of the nodes is not managed by this runtime,
+    /// - one of the nodes is restarted and its [on_resume] method failed, see [DataFlowInstance::start_nodes].
+    ///
+    /// [on_resume]: zenoh_flow_nodes::prelude::Node::on_resume()
+    #[tracing::instrument(name = "start-nodes", skip(self, id), fields(instance = %id))]
+    pub async fn try_start_nodes(&self, id: &InstanceId, nodes: &[NodeId]) -> Result<()> {
+        let instance = self.try_get_instance(id).await?;
+        let mut instance_guard = instance.write().await;
+
+        instance_guard.start_nodes(nodes).await?;
+
+        tracing::info!("started");
+
+        Ok(())
+    }
+
+    /// Attempts to abort the provided `nodes` of the [DataFlowInstance] identified by the provided `id`, returning how
+    /// each of them was [aborted](AbortOutcome).
+    ///
+    /// The nodes that are not running are left untouched. The [state](InstanceState) of the instance is not modified:
+    /// it keeps describing the instance as a whole.
+    ///
+    /// # Errors
+    ///
+    /// This method can fail for the following reasons:
+    /// - no data flow with the provided id was found,
+    /// - the data flow is in a failed state,
+    /// - one of the nodes is not managed by this runtime.
+    #[tracing::instrument(name = "abort-nodes", skip(self, id), fields(instance = %id))]
+    pub async fn try_abort_nodes(
+        &self,
+        id: &InstanceId,
+        nodes: &[NodeId],
+    ) -> Result<HashMap<NodeId, AbortOutcome>> {
+        let instance = self.try_get_instance(id).await?;
+        let mut instance_guard = instance.write().await;
+
+        let outcomes = instance_guard.abort_nodes(nodes).await?;
+
+        tracing::info!("aborted");
+
+        Ok(outcomes)
+    }
+
+    /// Attempts to (re-)start the nodes of the provided `group`, managed by this runtime, of the [DataFlowInstance]
+    /// identified by the provided `id`, all or none.
+    ///
+    /// See [DataFlowRecord::group_members] for the nodes that are part of a group and
+    /// [try_start_nodes](Runtime::try_start_nodes()) for how they are started.
+    ///
+    /// # Errors
+    ///
+    /// This method can fail for the following reasons:
+    /// - no data flow with the provided id was found,
+    /// - the data flow is in a failed state,
+    /// - no node of the data flow is part of the group,
+    /// - one of the nodes failed to start.
+    pub async fn try_start_group(&self, id: &InstanceId, group: &str) -> Result<()> {
+        let nodes = self.try_get_group_nodes(id, group).await?;
+        self.try_start_nodes(id, &nodes).await
+    }
+
+    /// Attempts to abort the nodes of the provided `group`, managed by this runtime, of the [DataFlowInstance]
+    /// identified by the provided `id`, returning how each of them was [aborted](AbortOutcome).
+    ///
+    /// See [DataFlowRecord::group_members] for the nodes that are part of a group.
+    ///
+    /// # Errors
+    ///
+    /// This method can fail for the following reasons:
+    /// - no data flow with the provided id was found,
+    /// - the data flow is in a failed state,
+    /// - no node of the data flow is part of the group.
+    pub async fn try_abort_group(
+        &self,
+        id: &InstanceId,
+        group: &str,
+    ) -> Result<HashMap<NodeId, AbortOutcome>> {
+        let nodes = self.try_get_group_nodes(id, group).await?;
+        self.try_abort_nodes(id, &nodes).await
+    }
+
+    /// Attempts to update the [Configuration] of the provided `node` of the [DataFlowInstance] identified by the
+    /// provided `id`.
+    ///
+    /// See [DataFlowInstance::update_node_configuration].
+    ///
+    /// # Errors
+    ///
+    /// This method can fail for the following reasons:
+    /// - no data flow with the provided id was found,
+    /// - the data flow is in a failed state,
+    /// - the node is not managed by this runtime,
+    /// - the node rejected the new configuration, in which case the previous one is kept.
+    #[tracing::instrument(name = "update-configuration", skip(self, id, configuration), fields(instance = %id))]
+    pub async fn try_update_node_configuration(
+        &self,
+        id: &InstanceId,
+        node: &NodeId,
+        configuration: Configuration,
+    ) -> Result<()> {
+        let instance = self.try_get_instance(id).await?;
+        let mut instance_guard = instance.write().await;
+
+        instance_guard
+            .update_node_configuration(node, configuration)
+            .await?;
+
+        tracing::info!("updated");
+
+        Ok(())
+    }
+
+    /// Returns the nodes of the provided `group` that are managed by this runtime.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the data flow cannot be [retrieved](Runtime::try_get_instance()) or if no
+    /// node of the data flow is part of the group.
+    async fn try_get_group_nodes(&self, id: &InstanceId, group: &str) -> Result<Vec<NodeId>> {
+        let instance = self.try_get_instance(id).await?;
+        let instance_guard = instance.read().await;
+
+        let members = instance_guard.group_members(group);
+        if members.is_empty() {
+            bail!(
+                "No node of the data flow is part of the group < {} >",
+                group
+            );
+        }
+
+        Ok(members
+            .into_iter()
+            .filter(|node| instance_guard.runners.contains_key(node))
+            .collect())
+    }
+
     /// Attempts to delete the [DataFlowInstance] identified by the provided `id`.
     ///
     /// # Errors
@@ -289,3 +513,331 @@ impl Runtime {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use zenoh_flow_descriptors::FlattenedDataFlowDescriptor;
+    use zenoh_flow_nodes::prelude::{anyhow, Context, Inputs, Node, Outputs};
+
+    struct TestOperator {
+        fail_on_resume: bool,
+    }
+
+    #[async_trait]
+    impl Node for TestOperator {
+        async fn iteration(&self) -> Result<()> {
+            async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(())
+        }
+
+        async fn on_resume(&self) -> Result<()> {
+            if self.fail_on_resume {
+                return Err(anyhow!("on_resume failed"));
+            }
+            Ok(())
+        }
+
+        async fn on_configuration_update(&self, configuration: &Configuration) -> Result<()> {
+            if configuration.get("reject").is_some() {
+                return Err(anyhow!("on_configuration_update failed"));
+            }
+            Ok(())
+        }
+
+        fn description(&self) -> &'static str {
+            "An operator that does nothing"
+        }
+
+        fn documentation_url(&self) -> Option<&'static str> {
+            Some("https://zenoh.io")
+        }
+    }
+
+    /// Returns a runtime managing an instance made of two inline Operators, where resuming the second one fails.
+    async fn new_runtime() -> (Runtime, InstanceId) {
+        let runtime = Runtime::builder("test-runtime")
+            .add_inline_operator(
+                "test-operator",
+                |_: Context, _: Configuration, _: Inputs, _: Outputs| {
+                    Box::pin(async {
+                        Ok(Arc::new(TestOperator {
+                            fail_on_resume: false,
+                        }) as Arc<dyn Node>)
+                    })
+                },
+            )
+            .add_inline_operator(
+                "failing-resume-operator",
+                |_: Context, _: Configuration, _: Inputs, _: Outputs| {
+                    Box::pin(async {
+                        Ok(Arc::new(TestOperator {
+                            fail_on_resume: true,
+                        }) as Arc<dyn Node>)
+                    })
+                },
+            )
+            .build()
+            .await
+            .expect("Failed to build runtime");
+
+        let flow = r#"
+name: test-flow
+
+sources: []
+sinks: []
+
+operators:
+  - id: operator-0
+    group: first
+    library: inline://test-operator
+    inputs: []
+    outputs:
+      - out-0
+
+  - id: operator-1
+    group: second
+    library: inline://failing-resume-operator
+    inputs:
+      - in-1
+    outputs: []
+
+links:
+  - from:
+      node: operator-0
+      output: out-0
+    to:
+      node: operator-1
+      input: in-1
+"#;
+        let flattened = serde_yaml::from_str::<FlattenedDataFlowDescriptor>(flow)
+            .expect("Failed to deserialize flow");
+        let record =
+            DataFlowRecord::try_new(&flattened, runtime.id()).expect("Failed to create record");
+        let instance_id = record.instance_id().clone();
+
+        runtime
+            .try_load_data_flow(record)
+            .await
+            .expect("Failed to load data flow");
+
+        (runtime, instance_id)
+    }
+
+    #[async_std::test]
+    async fn test_get_node_status() {
+        let (runtime, instance_id) = new_runtime().await;
+        let operator: NodeId = "operator-0".into();
+
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator).await
+        );
+        assert!(runtime
+            .get_node_status(&instance_id, &"unknown".into())
+            .await
+            .is_none());
+        assert!(runtime
+            .get_node_status(&InstanceId::from(uuid::Uuid::new_v4()), &operator)
+            .await
+            .is_none());
+
+        runtime
+            .try_start_instance(&instance_id)
+            .await
+            .expect("Failed to start instance");
+        assert_eq!(
+            Some(NodeStatus::Running),
+            runtime.get_node_status(&instance_id, &operator).await
+        );
+
+        runtime
+            .try_abort_instance(&instance_id)
+            .await
+            .expect("Failed to abort instance");
+        assert_eq!(
+            Some(NodeStatus::Aborted),
+            runtime.get_node_status(&instance_id, &operator).await
+        );
+    }
+
+    #[async_std::test]
+    async fn test_start_abort_nodes() {
+        let (runtime, instance_id) = new_runtime().await;
+        let operator_0: NodeId = "operator-0".into();
+        let operator_1: NodeId = "operator-1".into();
+
+        assert!(runtime
+            .try_start_nodes(&instance_id, &[operator_0.clone(), "unknown".into()])
+            .await
+            .is_err());
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+        assert!(runtime
+            .try_start_nodes(
+                &InstanceId::from(uuid::Uuid::new_v4()),
+                &[operator_0.clone()]
+            )
+            .await
+            .is_err());
+
+        runtime
+            .try_start_nodes(&instance_id, &[operator_1.clone()])
+            .await
+            .expect("Failed to start operator-1");
+        assert_eq!(
+            Some(NodeStatus::Running),
+            runtime.get_node_status(&instance_id, &operator_1).await
+        );
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+
+        let outcomes = runtime
+            .try_abort_nodes(&instance_id, &[operator_0.clone(), operator_1.clone()])
+            .await
+            .expect("Failed to abort nodes");
+        assert_eq!(
+            HashMap::from([
+                (operator_0.clone(), AbortOutcome::NotRunning),
+                (operator_1.clone(), AbortOutcome::Aborted)
+            ]),
+            outcomes
+        );
+        assert_eq!(
+            Some(NodeStatus::Aborted),
+            runtime.get_node_status(&instance_id, &operator_1).await
+        );
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+
+        // Resuming operator-1 fails: operator-0, started during the same call, must be back to its previous status.
+        assert!(runtime
+            .try_start_nodes(&instance_id, &[operator_0.clone(), operator_1.clone()])
+            .await
+            .is_err());
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+        assert_eq!(
+            Some(NodeStatus::Aborted),
+            runtime.get_node_status(&instance_id, &operator_1).await
+        );
+    }
+
+    #[async_std::test]
+    async fn test_start_abort_group() {
+        let (runtime, instance_id) = new_runtime().await;
+        let operator_0: NodeId = "operator-0".into();
+        let operator_1: NodeId = "operator-1".into();
+
+        assert!(runtime
+            .try_start_group(&instance_id, "unknown")
+            .await
+            .is_err());
+
+        runtime
+            .try_start_group(&instance_id, "first")
+            .await
+            .expect("Failed to start group");
+        assert_eq!(
+            Some(NodeStatus::Running),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_1).await
+        );
+
+        let outcomes = runtime
+            .try_abort_group(&instance_id, "first")
+            .await
+            .expect("Failed to abort group");
+        assert_eq!(Some(&AbortOutcome::Aborted), outcomes.get(&operator_0));
+        assert_eq!(
+            Some(NodeStatus::Aborted),
+            runtime.get_node_status(&instance_id, &operator_0).await
+        );
+        assert_eq!(
+            Some(NodeStatus::Loaded),
+            runtime.get_node_status(&instance_id, &operator_1).await
+        );
+    }
+
+    #[async_std::test]
+    async fn test_get_node_description() {
+        let (runtime, instance_id) = new_runtime().await;
+
+        assert_eq!(
+            Some(("An operator that does nothing", Some("https://zenoh.io"))),
+            runtime
+                .get_node_description(&instance_id, &"operator-0".into())
+                .await
+        );
+        assert!(runtime
+            .get_node_description(&instance_id, &"unknown".into())
+            .await
+            .is_none());
+        assert!(runtime
+            .get_node_description(
+                &InstanceId::from(uuid::Uuid::new_v4()),
+                &"operator-0".into()
+            )
+            .await
+            .is_none());
+    }
+
+    #[async_std::test]
+    async fn test_update_node_configuration() {
+        let (runtime, instance_id) = new_runtime().await;
+        let operator: NodeId = "operator-0".into();
+
+        let configuration =
+            serde_yaml::from_str::<Configuration>("threshold: 42").expect("Failed to parse");
+        runtime
+            .try_update_node_configuration(&instance_id, &operator, configuration.clone())
+            .await
+            .expect("Failed to update configuration");
+        let record = runtime
+            .try_get_record(&instance_id)
+            .await
+            .expect("Failed to get record");
+        assert_eq!(Some(&configuration), record.node_configuration(&operator));
+
+        let rejected =
+            serde_yaml::from_str::<Configuration>("reject: true").expect("Failed to parse");
+        assert!(runtime
+            .try_update_node_configuration(&instance_id, &operator, rejected)
+            .await
+            .is_err());
+        let record = runtime
+            .try_get_record(&instance_id)
+            .await
+            .expect("Failed to get record");
+        assert_eq!(Some(&configuration), record.node_configuration(&operator));
+
+        assert!(runtime
+            .try_update_node_configuration(
+                &instance_id,
+                &"unknown".into(),
+                Configuration::default()
+            )
+            .await
+            .is_err());
+        assert!(runtime
+            .try_update_node_configuration(
+                &InstanceId::from(uuid::Uuid::new_v4()),
+                &operator,
+                Configuration::default()
+            )
+            .await
+            .is_err());
+    }
+}
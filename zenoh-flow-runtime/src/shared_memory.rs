@@ -23,13 +23,16 @@ pub(crate) struct SharedMemory {
     session: Arc<Session>,
     manager: SharedMemoryManager,
     configuration: SharedMemoryConfiguration,
+    pub(crate) congestion_control: CongestionControl,
 }
 
 impl SharedMemory {
+    /// Creates a `SharedMemory` whose publications, on the provided `session`, use the provided `congestion_control`.
     pub(crate) fn new(
         id: &NodeId,
         session: Arc<Session>,
         shm_configuration: &SharedMemoryConfiguration,
+        congestion_control: CongestionControl,
     ) -> Self {
         Self {
             session,
@@ -39,6 +42,7 @@ impl SharedMemory {
             )
             .unwrap(),
             configuration: *shm_configuration,
+            congestion_control,
         }
     }
 
@@ -78,7 +82,7 @@ impl SharedMemory {
 
         self.session
             .put(key_expr, shm_buffer)
-            .congestion_control(CongestionControl::Block)
+            .congestion_control(self.congestion_control)
             .res()
             .await
             .map_err(|e| {